@@ -1,95 +1,303 @@
 use crate::args::Args;
 use crate::contentstack::{Client, ContentTypeResponse, GlobalFieldResponse};
-use crate::error::Result;
-use crate::generator::{Field, GenerateConfig, Generator, TypescriptGenerator};
+use crate::error::{Error, Result};
+use crate::generator::{
+    Ambient, BuiltinsMode, DateType, DeclarationStyle, Field, Formatting, GenerateConfig,
+    Generator, GeneratorOptions, GoGenerator, Indent, JsonSchemaGenerator, PythonGenerator,
+    QuoteStyle, ReferenceStyle, RustGenerator, SelectStyle, TypescriptGenerator, TypescriptOptions,
+    ZodGenerator, assign_paths,
+};
+use crate::order::sort_fields;
+use crate::target::Target;
 use rayon::prelude::*;
+use std::collections::HashSet;
 use std::fs;
+use std::path::Path;
 
 pub struct App {
     client: Client,
     args: Args,
-    generator: TypescriptGenerator,
+    generators: Vec<(Target, Box<dyn Generator>)>,
 }
 
+/// File name (without extension) of single-file output written to `--out-dir`
+const DEFAULT_FILE_STEM: &str = "contentstack";
+
 impl App {
     pub fn new(args: Args) -> Result<Self> {
         let client = Client::new(args.region.clone(), &args.api_key, &args.access_token)?;
-        let generator = TypescriptGenerator::new(args.prefix.as_deref(), args.postfix.as_deref());
+        Self::validate_options(&args)?;
+        let common = Self::generator_options(&args);
+        let options = Self::typescript_options(&args, common.clone());
+        let generators = args
+            .targets
+            .iter()
+            .map(|target| {
+                let generator: Box<dyn Generator> = match target {
+                    Target::Typescript => {
+                        Box::new(TypescriptGenerator::with_options(options.clone()))
+                    }
+                    Target::Zod => Box::new(ZodGenerator::with_options(options.clone())),
+                    Target::JsonSchema => {
+                        Box::new(JsonSchemaGenerator::with_options(common.clone()))
+                    }
+                    Target::Rust => Box::new(RustGenerator::with_options(common.clone())),
+                    Target::Go => {
+                        Box::new(GoGenerator::with_options(common.clone(), &args.go_package))
+                    }
+                    Target::Python => Box::new(PythonGenerator::with_options(
+                        common.clone(),
+                        args.python_style.clone(),
+                    )),
+                };
+                (target.clone(), generator)
+            })
+            .collect();
 
         Ok(Self {
             client,
             args,
-            generator,
+            generators,
         })
     }
 
+    /// Rejects option combinations that would produce broken output or be silently ignored
+    fn validate_options(args: &Args) -> Result<()> {
+        let typescript = args.targets.contains(&Target::Typescript);
+        let zod = args.targets.contains(&Target::Zod);
+
+        // Formatting and date types apply to the TypeScript source Zod schemas are written as too
+        let formatting_flags = [
+            ("--quotes", !matches!(args.quotes, QuoteStyle::Single)),
+            ("--indent-width", args.indent_width != 2),
+            ("--use-tabs", args.use_tabs),
+            ("--no-semicolons", args.no_semicolons),
+            ("--no-trailing-commas", args.no_trailing_commas),
+            ("--date-type", args.date_type != DateType::String),
+        ];
+        let typescript_flags = [
+            (
+                "--references",
+                !matches!(args.references, ReferenceStyle::Union),
+            ),
+            (
+                "--declaration",
+                !matches!(args.declaration, DeclarationStyle::Interface),
+            ),
+            ("--selects", !matches!(args.selects, SelectStyle::Inline)),
+            ("--number-type", args.number_type.is_some()),
+            ("--file-type", args.file_type.is_some()),
+            ("--readonly", args.readonly),
+            ("--builtins", !matches!(args.builtins, BuiltinsMode::All)),
+            ("--builtins-from", args.builtins_from.is_some()),
+            ("--editable-tags", args.editable_tags),
+            ("--field-paths", args.field_paths),
+            ("--type-guards", args.type_guards),
+            ("--dts", args.dts),
+            ("--declare-module", args.declare_module.is_some()),
+            ("--declare-namespace", args.declare_namespace.is_some()),
+        ];
+        if let Some((flag, _)) = formatting_flags
+            .iter()
+            .find(|(_, set)| *set && !typescript && !zod)
+        {
+            return Err(Error::Config(format!(
+                "{flag} only applies to --target typescript and --target zod"
+            )));
+        }
+        if let DateType::Custom(custom) = &args.date_type
+            && zod
+        {
+            return Err(Error::Config(format!(
+                "--date-type {custom} has no Zod schema; use string, date or branded with --target zod"
+            )));
+        }
+        if let Some((flag, _)) = typescript_flags.iter().find(|(_, set)| *set && !typescript) {
+            return Err(Error::Config(format!(
+                "{flag} only applies to --target typescript"
+            )));
+        }
+
+        let ambient = args.dts || args.declare_module.is_some() || args.declare_namespace.is_some();
+        if !ambient {
+            return Ok(());
+        }
+        // Ambient declarations cannot introduce the runtime values behind these styles
+        if matches!(args.selects, SelectStyle::Const | SelectStyle::Enum) {
+            return Err(Error::Config(String::from(
+                "--selects const and --selects enum declare runtime values; use --selects inline with ambient declarations",
+            )));
+        }
+        match &args.output {
+            Some(path) if args.targets.len() == 1 && !path.ends_with(".d.ts") => {
+                Err(Error::Config(format!(
+                    "ambient declarations must be written to a .d.ts file, not {path}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn generator_options(args: &Args) -> GeneratorOptions {
+        GeneratorOptions {
+            prefix: args.prefix.clone().unwrap_or_default(),
+            postfix: args.postfix.clone().unwrap_or_default(),
+            affix_builtins: args.affix_builtins,
+            nullability: args.nullability.clone(),
+            assume_empty: args.assume_empty,
+        }
+    }
+
+    fn typescript_options(args: &Args, common: GeneratorOptions) -> TypescriptOptions {
+        TypescriptOptions {
+            common,
+            references: args.references.clone(),
+            declaration: args.declaration.clone(),
+            selects: args.selects.clone(),
+            date_type: args.date_type.clone(),
+            number_type: args.number_type.clone(),
+            file_type: args.file_type.clone(),
+            readonly: args.readonly,
+            ambient: match (&args.declare_module, &args.declare_namespace) {
+                (Some(name), _) => Ambient::Module(name.clone()),
+                (None, Some(name)) => Ambient::Namespace(name.clone()),
+                (None, None) if args.dts => Ambient::Declarations,
+                (None, None) => Ambient::None,
+            },
+            builtins: args.builtins.clone(),
+            builtins_import: args.builtins_from.clone(),
+            editable_tags: args.editable_tags,
+            field_paths: args.field_paths,
+            type_guards: args.type_guards,
+            formatting: Formatting {
+                indent: if args.use_tabs {
+                    Indent::Tabs
+                } else {
+                    Indent::Spaces(args.indent_width)
+                },
+                quotes: args.quotes.clone(),
+                semicolons: !args.no_semicolons,
+                trailing_commas: !args.no_trailing_commas,
+            },
+        }
+    }
+
     pub fn run(&self) -> Result<()> {
         let content_types = self.client.fetch_content_types()?;
         let global_fields = self.client.fetch_global_fields()?;
 
-        let typescript_code = self.generate_typescript(&content_types, &global_fields)?;
+        let (global_definitions, content_definitions) =
+            self.convert_definitions(&content_types, &global_fields)?;
 
-        self.output_result(&typescript_code)?;
+        // Checked up front so a clash in one target leaves no output from the others
+        for (_, generator) in &self.generators {
+            generator.check_names(&global_definitions, &content_definitions)?;
+        }
+        for (target, generator) in &self.generators {
+            match &self.args.out_dir {
+                Some(dir) => {
+                    let modules = generator
+                        .generate_modules(&global_definitions, &content_definitions)
+                        .unwrap_or_else(|| {
+                            vec![(
+                                format!("{DEFAULT_FILE_STEM}.{}", generator.get_file_extension()),
+                                generator
+                                    .generate_document(&global_definitions, &content_definitions),
+                            )]
+                        });
+                    self.output_modules(target, dir, &modules)?;
+                }
+                None => {
+                    let content =
+                        generator.generate_document(&global_definitions, &content_definitions);
+                    self.output_result(target, &generator.get_file_extension(), &content)?;
+                }
+            }
+        }
 
         Ok(())
     }
 
-    fn generate_typescript(
+    /// Converts global fields and content types in parallel and puts them in the configured order
+    fn convert_definitions(
         &self,
         content_types: &ContentTypeResponse,
         global_fields: &GlobalFieldResponse,
-    ) -> Result<String> {
-        let mut output = String::new();
-
-        // Add builtin types at the beginning
-        output.push_str(&self.generator.get_builtins());
-        output.push('\n');
-
-        // Generate global field types in parallel
-        let global_interfaces: std::result::Result<Vec<String>, crate::error::Error> =
-            global_fields
-                .global_fields
-                .par_iter()
-                .map(|global_field| {
-                    let fields = self.convert_schema_to_fields(&global_field.schema)?;
-                    let config = GenerateConfig {
-                        name: global_field.uid.clone(),
-                        description: global_field.description.clone(),
-                        fields,
-                    };
-                    let interface = self.generator.generate_interface(&config);
-                    Ok(interface)
-                })
-                .collect();
+    ) -> Result<(Vec<GenerateConfig>, Vec<GenerateConfig>)> {
+        let mut global_definitions = global_fields
+            .global_fields
+            .par_iter()
+            .map(|global_field| {
+                self.convert_definition(
+                    false,
+                    &global_field.uid,
+                    global_field.title.as_ref(),
+                    global_field.description.as_ref(),
+                    self.get_editor_url("global-fields", &global_field.uid),
+                    &global_field.schema,
+                )
+            })
+            .collect::<Result<Vec<GenerateConfig>>>()?;
+        let mut content_definitions = content_types
+            .content_types
+            .par_iter()
+            .map(|content_type| {
+                self.convert_definition(
+                    true,
+                    &content_type.uid,
+                    content_type.title.as_ref(),
+                    content_type.description.as_ref(),
+                    self.get_editor_url("content-type", &content_type.uid),
+                    &content_type.schema,
+                )
+            })
+            .collect::<Result<Vec<GenerateConfig>>>()?;
 
-        for interface in global_interfaces? {
-            output.push_str(&interface);
-            output.push('\n');
-        }
+        self.args.order.sort(&mut global_definitions);
+        self.args.order.sort(&mut content_definitions);
 
-        // Generate content type interfaces in parallel
-        let content_interfaces: std::result::Result<Vec<String>, crate::error::Error> =
-            content_types
-                .content_types
-                .par_iter()
-                .map(|content_type| {
-                    let fields = self.convert_schema_to_fields(&content_type.schema)?;
-                    let config = GenerateConfig {
-                        name: content_type.uid.clone(),
-                        description: content_type.description.clone(),
-                        fields,
-                    };
-                    let interface = self.generator.generate_interface(&config);
-                    Ok(interface)
-                })
-                .collect();
+        Ok((global_definitions, content_definitions))
+    }
+
+    /// Link to a content type or global field in the CMS editor, when editor links are enabled
+    fn get_editor_url(&self, kind: &str, uid: &str) -> Option<String> {
+        self.args.editor_links.then(|| {
+            format!(
+                "{}/#!/stack/{}/{kind}/{uid}/content-type-builder",
+                self.args.region.app_url(),
+                self.args.api_key.trim()
+            )
+        })
+    }
 
-        for interface in content_interfaces? {
-            output.push_str(&interface);
-            output.push('\n');
+    fn convert_definition(
+        &self,
+        is_content_type: bool,
+        uid: &str,
+        title: Option<&String>,
+        description: Option<&String>,
+        editor_url: Option<String>,
+        schema: &[crate::contentstack::response::Schema],
+    ) -> Result<GenerateConfig> {
+        let mut fields = self.convert_schema_to_fields(schema)?;
+        if self.args.sort_fields {
+            sort_fields(&mut fields);
+        }
+        assign_paths(&mut fields, &[uid.to_string()]);
+        if is_content_type {
+            for field in &mut fields {
+                let config = field.config_mut();
+                config.entry_title = config.name == "title";
+            }
         }
 
-        Ok(output)
+        Ok(GenerateConfig {
+            name: uid.to_string(),
+            title: title.cloned(),
+            description: description.cloned(),
+            editor_url,
+            fields,
+        })
     }
 
     fn convert_schema_to_fields(
@@ -118,10 +326,15 @@ impl App {
                 .as_ref()
                 .and_then(|m| m.ref_multiple)
                 .unwrap_or(false);
+        let display_name = field.display_name.clone();
         let description = field
             .field_metadata
             .as_ref()
             .and_then(|m| m.description.clone());
+        let instruction = field
+            .field_metadata
+            .as_ref()
+            .and_then(|m| m.instruction.clone());
 
         let converted_field = match field.data_type {
             DataType::Text => {
@@ -136,8 +349,10 @@ impl App {
                 });
                 Field::Text(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     enumeration,
                     multiple,
                     ..Default::default()
@@ -155,8 +370,10 @@ impl App {
                 });
                 Field::Number(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     enumeration,
                     multiple,
                     ..Default::default()
@@ -164,36 +381,46 @@ impl App {
             }
             DataType::Boolean => Field::Bool(FieldConfig {
                 name,
+                display_name,
                 optional,
                 description,
+                instruction,
                 multiple,
                 ..Default::default()
             }),
             DataType::Date => Field::Date(FieldConfig {
                 name,
+                display_name,
                 optional,
                 description,
+                instruction,
                 multiple,
                 ..Default::default()
             }),
             DataType::File => Field::File(FieldConfig {
                 name,
+                display_name,
                 optional,
                 description,
+                instruction,
                 multiple,
                 ..Default::default()
             }),
             DataType::Link => Field::Link(FieldConfig {
                 name,
+                display_name,
                 optional,
                 description,
+                instruction,
                 multiple,
                 ..Default::default()
             }),
             DataType::Json => Field::Json(FieldConfig {
                 name,
+                display_name,
                 optional,
                 description,
+                instruction,
                 multiple,
                 ..Default::default()
             }),
@@ -205,8 +432,10 @@ impl App {
                 };
                 Field::Reference(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     reference_to,
                     multiple,
                     ..Default::default()
@@ -220,8 +449,10 @@ impl App {
                 };
                 Field::Global(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     reference_to,
                     multiple,
                     ..Default::default()
@@ -235,8 +466,10 @@ impl App {
                 };
                 Field::Group(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     fields,
                     multiple,
                     ..Default::default()
@@ -252,8 +485,10 @@ impl App {
                 }
                 Field::Blocks(FieldConfig {
                     name,
+                    display_name,
                     optional,
                     description,
+                    instruction,
                     fields: vec![], // Will be handled differently in generator
                     multiple,
                     block_types: Some(block_types),
@@ -265,11 +500,17 @@ impl App {
         Ok(converted_field)
     }
 
-    fn output_result(&self, content: &str) -> Result<()> {
+    fn output_result(&self, target: &Target, extension: &str, content: &str) -> Result<()> {
         match &self.args.output {
             Some(path) => {
-                fs::write(path, content)?;
-                println!("TypeScript types written to: {path}");
+                // Several targets share the output path, each with its own extension
+                let path = if self.generators.len() > 1 {
+                    Self::replace_extension(path, extension)
+                } else {
+                    path.clone()
+                };
+                fs::write(&path, content)?;
+                println!("{} written to: {path}", target.label());
             }
             None => {
                 println!("{content}");
@@ -278,4 +519,49 @@ impl App {
 
         Ok(())
     }
+
+    fn output_modules(
+        &self,
+        target: &Target,
+        dir: &str,
+        modules: &[(String, String)],
+    ) -> Result<()> {
+        Self::check_module_names(modules)?;
+
+        let dir = Path::new(dir);
+        fs::create_dir_all(dir)?;
+        for (file_name, content) in modules {
+            fs::write(dir.join(file_name), content)?;
+        }
+        println!("{} written to: {}", target.label(), dir.display());
+
+        Ok(())
+    }
+
+    /// Fails when two modules would be written to the same file, e.g. a content type with uid `index`
+    pub fn check_module_names(modules: &[(String, String)]) -> Result<()> {
+        let mut file_names = HashSet::new();
+        match modules
+            .iter()
+            .find(|(file_name, _)| !file_names.insert(file_name))
+        {
+            Some((file_name, _)) => Err(Error::Output(format!(
+                "more than one module would be written to {file_name}; rename the content type or global field, or use --output"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// `path` with everything after the first dot of its file name replaced by `extension`
+    pub fn replace_extension(path: &str, extension: &str) -> String {
+        let path = Path::new(path);
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stem = file_name.split('.').next().unwrap_or_default();
+        path.with_file_name(format!("{stem}.{extension}"))
+            .to_string_lossy()
+            .into_owned()
+    }
 }
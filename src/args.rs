@@ -1,4 +1,10 @@
+use crate::generator::{
+    BuiltinsMode, DateType, DeclarationStyle, Nullability, PythonStyle, QuoteStyle, ReferenceStyle,
+    SelectStyle, go,
+};
+use crate::order::Order;
 use crate::region::Region;
+use crate::target::Target;
 use clap::Parser;
 
 #[derive(Parser, Debug)]
@@ -13,9 +19,25 @@ pub struct Args {
     pub access_token: String,
 
     /// Optional, The path that cli should dump the result. if none is passed it will print into std
-    #[arg(short = 'o', long)]
+    #[arg(short = 'o', long, conflicts_with = "out_dir")]
     pub output: Option<String>,
 
+    /// Optional, Write one module per content type and global field into this directory, with an index barrel
+    #[arg(long)]
+    pub out_dir: Option<String>,
+
+    /// Optional, Output formats to generate, repeat for several; with `--output` each gets its own extension
+    #[arg(long = "target", value_enum, default_values_t = [Target::Typescript])]
+    pub targets: Vec<Target>,
+
+    /// Optional, Package name of the Go output
+    #[arg(long, default_value = go::DEFAULT_PACKAGE, value_parser = go::parse_package)]
+    pub go_package: String,
+
+    /// Optional, Whether the Python output declares Pydantic models or `TypedDict`s
+    #[arg(long, default_value = "pydantic")]
+    pub python_style: PythonStyle,
+
     /// Optional, The region for api call
     #[arg(short = 'r', long, default_value = "europe")]
     pub region: Region,
@@ -25,4 +47,108 @@ pub struct Args {
 
     #[arg(long)]
     pub postfix: Option<String>,
+
+    /// Optional, Number of spaces per indentation level
+    #[arg(long, default_value_t = 2)]
+    pub indent_width: usize,
+
+    /// Optional, Indent with tabs instead of spaces
+    #[arg(long)]
+    pub use_tabs: bool,
+
+    /// Optional, Quotes around string literals and module specifiers
+    #[arg(long, default_value = "single")]
+    pub quotes: QuoteStyle,
+
+    /// Optional, Omit semicolons after statements and members
+    #[arg(long)]
+    pub no_semicolons: bool,
+
+    /// Optional, Omit the comma after the last enum member
+    #[arg(long)]
+    pub no_trailing_commas: bool,
+
+    /// Optional, Type the `$` map of `data-cslp` attributes added by Live Preview's `addEditableTags`
+    #[arg(long)]
+    pub editable_tags: bool,
+
+    /// Optional, Emit unions of field paths and reference field paths per content type
+    #[arg(long)]
+    pub field_paths: bool,
+
+    /// Optional, Emit runtime `isX` type guards per content type and an `assertContentType` helper
+    #[arg(long, conflicts_with_all = ["dts", "declare_module", "declare_namespace"])]
+    pub type_guards: bool,
+
+    /// Optional, Add `@see` links to the CMS editor to the docs of content types and global fields
+    #[arg(long)]
+    pub editor_links: bool,
+
+    /// Optional, Which builtin types (ContentstackFile, ...) are declared
+    #[arg(long, default_value = "all")]
+    pub builtins: BuiltinsMode,
+
+    /// Optional, Import builtin types from this module instead of declaring them
+    #[arg(long, conflicts_with = "declare_namespace")]
+    pub builtins_from: Option<String>,
+
+    /// Optional, Apply prefix and postfix to builtin types (ContentstackFile, ...) as well
+    #[arg(long)]
+    pub affix_builtins: bool,
+
+    /// Optional, The order in which interfaces are written
+    #[arg(long, default_value = "schema")]
+    pub order: Order,
+
+    /// Optional, Sort fields within each interface by uid
+    #[arg(long)]
+    pub sort_fields: bool,
+
+    /// Optional, How reference fields are typed
+    #[arg(long, default_value = "union")]
+    pub references: ReferenceStyle,
+
+    /// Optional, Declare object types as interfaces or type aliases
+    #[arg(long, default_value = "interface")]
+    pub declaration: DeclarationStyle,
+
+    /// Optional, How select field choices are declared
+    #[arg(long, default_value = "inline")]
+    pub selects: SelectStyle,
+
+    /// Optional, Type of date fields: `string`, `date`, `branded` or any TypeScript type
+    #[arg(long, default_value = "string")]
+    pub date_type: DateType,
+
+    /// Optional, Type of number fields, e.g. `Decimal`
+    #[arg(long)]
+    pub number_type: Option<String>,
+
+    /// Optional, Type of file fields instead of `ContentstackFile`
+    #[arg(long)]
+    pub file_type: Option<String>,
+
+    /// Optional, How optional fields are typed
+    #[arg(long, default_value = "optional")]
+    pub nullability: Nullability,
+
+    /// Optional, Treat every field except the entry title as possibly empty
+    #[arg(long)]
+    pub assume_empty: bool,
+
+    /// Optional, Emit readonly properties and arrays
+    #[arg(long)]
+    pub readonly: bool,
+
+    /// Optional, Emit ambient `.d.ts` declarations instead of a regular module
+    #[arg(long)]
+    pub dts: bool,
+
+    /// Optional, Wrap `.d.ts` declarations in `declare module '<NAME>'`
+    #[arg(long, conflicts_with_all = ["declare_namespace", "out_dir"])]
+    pub declare_module: Option<String>,
+
+    /// Optional, Wrap `.d.ts` declarations in `declare namespace <NAME>`
+    #[arg(long, conflicts_with = "out_dir")]
+    pub declare_namespace: Option<String>,
 }
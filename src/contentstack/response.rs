@@ -13,6 +13,7 @@ pub struct GlobalFieldResponse {
 #[derive(Debug, Deserialize)]
 pub struct GlobalField {
     pub uid: String,
+    pub title: Option<String>,
     pub description: Option<String>,
     pub schema: Vec<Schema>,
 }
@@ -20,6 +21,7 @@ pub struct GlobalField {
 #[derive(Debug, Deserialize)]
 pub struct ContentType {
     pub uid: String,
+    pub title: Option<String>,
     pub description: Option<String>,
     pub schema: Vec<Schema>,
 }
@@ -60,6 +62,7 @@ pub enum ReferenceTo {
 #[derive(Debug, Deserialize)]
 pub struct Schema {
     pub uid: String,
+    pub display_name: Option<String>,
     pub data_type: DataType,
     pub schema: Option<Vec<Schema>>,
     pub multiple: bool,
@@ -92,6 +95,7 @@ pub enum ChoiceValue {
 #[derive(Debug, Deserialize)]
 pub struct FieldMetaData {
     pub description: Option<String>,
+    pub instruction: Option<String>,
     pub ref_multiple: Option<bool>,
 }
 
@@ -5,6 +5,10 @@ use std::io;
 pub enum Error {
     Contentstack(Box<ContentstackError>),
     Io(io::Error),
+    /// Options that cannot be used together
+    Config(String),
+    /// Generated output that cannot be written as requested
+    Output(String),
 }
 
 impl std::fmt::Display for Error {
@@ -12,6 +16,8 @@ impl std::fmt::Display for Error {
         match self {
             Error::Contentstack(e) => write!(f, "Contentstack error: {e}"),
             Error::Io(e) => write!(f, "IO error: {e}"),
+            Error::Config(message) => write!(f, "Configuration error: {message}"),
+            Error::Output(message) => write!(f, "Output error: {message}"),
         }
     }
 }
@@ -21,6 +27,7 @@ impl std::error::Error for Error {
         match self {
             Error::Contentstack(e) => Some(e.as_ref()),
             Error::Io(e) => Some(e),
+            Error::Config(_) | Error::Output(_) => None,
         }
     }
 }
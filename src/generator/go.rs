@@ -0,0 +1,660 @@
+use crate::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, get_definition_doc_paragraphs,
+    get_field_doc_paragraphs, get_line_comment, walk_fields,
+};
+use std::collections::HashSet;
+
+/// Package of the generated file unless configured otherwise
+pub const DEFAULT_PACKAGE: &str = "contentstack";
+
+/// Reserved words that cannot name a package
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Words written in all caps in Go identifiers
+const INITIALISMS: &[&str] = &[
+    "acl", "api", "html", "http", "https", "id", "json", "rte", "seo", "uid", "uri", "url",
+];
+
+/// Go structs with `encoding/json` tags
+pub struct GoGenerator {
+    options: GeneratorOptions,
+    package: String,
+}
+
+impl GoGenerator {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self::with_options(GeneratorOptions::new(prefix, postfix), DEFAULT_PACKAGE)
+    }
+
+    pub fn with_options(options: GeneratorOptions, package: &str) -> Self {
+        Self {
+            options,
+            package: package.to_string(),
+        }
+    }
+
+    /// Indents every non-empty line of `code` by one tab
+    fn indent(&self, code: &str) -> String {
+        let mut output = String::new();
+        for line in code.lines() {
+            if !line.is_empty() {
+                output.push('\t');
+                output.push_str(line);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// `//` comment from paragraphs, empty when there is nothing to say
+    fn get_doc_comment(&self, paragraphs: &[String]) -> String {
+        get_line_comment("//", paragraphs)
+    }
+
+    fn get_field_doc(&self, config: &FieldConfig) -> String {
+        self.get_doc_comment(&get_field_doc_paragraphs(config))
+    }
+
+    fn get_definition_doc(&self, config: &GenerateConfig) -> String {
+        let mut paragraphs = get_definition_doc_paragraphs(config);
+        paragraphs.extend(config.editor_url.clone());
+        self.get_doc_comment(&paragraphs)
+    }
+
+    /// Exported identifier for a uid or select choice, `None` when nothing is left of it
+    fn to_exported_name(&self, value: &str) -> Option<String> {
+        let name: String = value
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let part = part.to_ascii_lowercase();
+                if INITIALISMS.contains(&part.as_str()) {
+                    return part.to_ascii_uppercase();
+                }
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        if name.is_empty() { None } else { Some(name) }
+    }
+
+    /// Struct field name for a uid
+    fn get_field_name(&self, uid: &str) -> String {
+        match self.to_exported_name(uid) {
+            Some(name) if name.starts_with(|c: char| c.is_ascii_alphabetic()) => name,
+            Some(name) => format!("Field{name}"),
+            None => String::from("Field"),
+        }
+    }
+
+    /// Struct field name for a uid, unique within `used`
+    fn get_unique_field_name(&self, uid: &str, used: &mut HashSet<String>) -> String {
+        let name = self.get_field_name(uid);
+        let mut unique = name.clone();
+        let mut counter = 2;
+        while !used.insert(unique.clone()) {
+            unique = format!("{name}{counter}");
+            counter += 1;
+        }
+        unique
+    }
+
+    /// `{TypeName}{Choice}` constant name, unique within `used`
+    fn get_constant_name(
+        &self,
+        type_name: &str,
+        value: &str,
+        used: &mut HashSet<String>,
+    ) -> String {
+        let suffix = self
+            .to_exported_name(value)
+            .unwrap_or_else(|| String::from("Empty"));
+        let name = format!("{type_name}{suffix}");
+
+        let mut unique = name.clone();
+        let mut counter = 2;
+        while !used.insert(unique.clone()) {
+            unique = format!("{name}{counter}");
+            counter += 1;
+        }
+        unique
+    }
+
+    /// Uid path of a field joined with `_`, the base of the names of its nested types
+    fn get_nested_name(&self, config: &FieldConfig) -> String {
+        if config.path.is_empty() {
+            config.name.clone()
+        } else {
+            config.path.join("_")
+        }
+    }
+
+    /// Name of the type declared for a select, group or modular blocks field
+    fn get_nested_type_name(&self, config: &FieldConfig) -> String {
+        self.options.get_type_name(&self.get_nested_name(config))
+    }
+
+    fn get_block_type_name(&self, config: &FieldConfig, block_uid: &str) -> String {
+        self.options
+            .get_type_name(&format!("{}_{block_uid}", self.get_nested_name(config)))
+    }
+
+    fn get_json_type(&self) -> String {
+        self.options.get_builtin_name("ContentstackJson")
+    }
+
+    /// Numeric choices of a number select, which are the only ones Go constants can hold
+    fn get_number_choices<'a>(&self, config: &'a FieldConfig) -> Vec<&'a String> {
+        config
+            .enumeration
+            .iter()
+            .flatten()
+            .filter(|choice| choice.parse::<f64>().is_ok_and(f64::is_finite))
+            .collect()
+    }
+
+    /// Struct field with its JSON tag; optional values are pointers unless `nilable` already
+    fn generate_member(
+        &self,
+        config: &FieldConfig,
+        field_type: &str,
+        nilable: bool,
+        used: &mut HashSet<String>,
+    ) -> String {
+        let optional = self.options.is_optional(config);
+        let field_type = if optional && !nilable {
+            format!("*{field_type}")
+        } else {
+            field_type.to_string()
+        };
+        let omitempty = if optional { ",omitempty" } else { "" };
+        format!(
+            "{}{} {field_type} `json:\"{}{omitempty}\"`",
+            self.get_field_doc(config),
+            self.get_unique_field_name(&config.name, used),
+            config.name
+        )
+    }
+
+    fn generate_property(&self, config: &FieldConfig, field_type: &str, nilable: bool) -> String {
+        let (field_type, nilable) = self.get_multiple_type(config, field_type, nilable);
+        self.generate_member(config, &field_type, nilable, &mut HashSet::new())
+    }
+
+    /// Slice type of a multiple field, which is nilable, or the type as it is
+    fn get_multiple_type(
+        &self,
+        config: &FieldConfig,
+        field_type: &str,
+        nilable: bool,
+    ) -> (String, bool) {
+        if config.multiple {
+            (format!("[]{field_type}"), true)
+        } else {
+            (field_type.to_string(), nilable)
+        }
+    }
+
+    /// Struct type declaration from rendered fields
+    fn generate_struct(&self, docs: &str, name: &str, members: &[String]) -> String {
+        if members.is_empty() {
+            return format!("{docs}type {name} struct{{}}\n");
+        }
+        format!(
+            "{docs}type {name} struct {{\n{}}}\n",
+            self.indent(&members.join("\n"))
+        )
+    }
+
+    /// Fields of a struct, suffixed where two uids map onto the same field name
+    fn generate_members(&self, fields: &[Field], used: &mut HashSet<String>) -> Vec<String> {
+        fields
+            .iter()
+            .map(|field| {
+                let (config, field_type, nilable) = self.get_member_type(field);
+                self.generate_member(config, &field_type, nilable, used)
+            })
+            .collect()
+    }
+
+    /// Config of a field, the type of its struct field and whether that type is nilable
+    fn get_member_type<'a>(&self, field: &'a Field) -> (&'a FieldConfig, String, bool) {
+        let (config, field_type, nilable) = match field {
+            Field::Text(config) => (config, self.get_text_type(config), false),
+            Field::Number(config) => (config, self.get_number_type(config), false),
+            Field::Date(config) => (config, String::from("string"), false),
+            Field::Bool(config) => (config, String::from("bool"), false),
+            Field::File(config) => (
+                config,
+                self.options.get_builtin_name("ContentstackFile"),
+                false,
+            ),
+            Field::Link(config) => (
+                config,
+                self.options.get_builtin_name("ContentstackLink"),
+                false,
+            ),
+            Field::Json(config) => (config, self.get_json_type(), true),
+            Field::Reference(config) => {
+                let (reference_type, nilable) = self.get_reference_type(config);
+                (config, reference_type, nilable)
+            }
+            Field::Global(config) => {
+                let (global_type, nilable) = self.get_global_field_type(config);
+                (config, global_type, nilable)
+            }
+            // Blocks are always a list
+            Field::Blocks(config) => {
+                return (config, format!("[]{}", self.get_blocks_type(config)), true);
+            }
+            Field::Group(config) => {
+                let (group_type, nilable) = self.get_group_type(config);
+                (config, group_type, nilable)
+            }
+        };
+        let (field_type, nilable) = self.get_multiple_type(config, &field_type, nilable);
+        (config, field_type, nilable)
+    }
+
+    fn get_text_type(&self, config: &FieldConfig) -> String {
+        match &config.enumeration {
+            Some(choices) if !choices.is_empty() => self.get_nested_type_name(config),
+            _ => String::from("string"),
+        }
+    }
+
+    fn get_number_type(&self, config: &FieldConfig) -> String {
+        if self.get_number_choices(config).is_empty() {
+            String::from("float64")
+        } else {
+            self.get_nested_type_name(config)
+        }
+    }
+
+    /// Type of a reference field and whether it is nilable
+    fn get_reference_type(&self, config: &FieldConfig) -> (String, bool) {
+        let reference = self.options.get_builtin_name("Reference");
+        match config.reference_to.as_slice() {
+            [] => (self.get_json_type(), true),
+            [uid] => (
+                format!("{reference}[{}]", self.options.get_type_name(uid)),
+                false,
+            ),
+            // Entries of several content types are left for the caller to decode by uid
+            _ => (format!("{reference}[json.RawMessage]"), false),
+        }
+    }
+
+    /// Type of a global field and whether it is nilable
+    fn get_global_field_type(&self, config: &FieldConfig) -> (String, bool) {
+        match config.reference_to.as_slice() {
+            [uid] => (self.options.get_type_name(uid), false),
+            _ => (self.get_json_type(), true),
+        }
+    }
+
+    fn get_blocks_type(&self, config: &FieldConfig) -> String {
+        match &config.block_types {
+            Some(block_types) if !block_types.is_empty() => self.get_nested_type_name(config),
+            _ => self.get_json_type(),
+        }
+    }
+
+    /// Type of a group field and whether it is nilable
+    fn get_group_type(&self, config: &FieldConfig) -> (String, bool) {
+        if config.fields.is_empty() {
+            (self.get_json_type(), true)
+        } else {
+            (self.get_nested_type_name(config), false)
+        }
+    }
+
+    /// Named type of a select with one constant per choice
+    fn generate_select_declaration(
+        &self,
+        config: &FieldConfig,
+        base_type: &str,
+        values: &[(String, String)],
+    ) -> String {
+        let type_name = self.get_nested_type_name(config);
+        let mut used = HashSet::new();
+        let constants: Vec<String> = values
+            .iter()
+            .map(|(choice, literal)| {
+                format!(
+                    "{} {type_name} = {literal}",
+                    self.get_constant_name(&type_name, choice, &mut used)
+                )
+            })
+            .collect();
+        format!(
+            "type {type_name} {base_type}\n\nconst (\n{})\n",
+            self.indent(&constants.join("\n"))
+        )
+    }
+
+    fn generate_group_declaration(&self, config: &FieldConfig) -> String {
+        self.generate_struct(
+            "",
+            &self.get_nested_type_name(config),
+            &self.generate_members(&config.fields, &mut HashSet::new()),
+        )
+    }
+
+    /// Struct per block plus an item type with one pointer per block, matching `[{ "block_uid": { ... } }]`
+    fn generate_blocks_declaration(
+        &self,
+        config: &FieldConfig,
+        block_types: &[(String, Vec<Field>)],
+    ) -> String {
+        let mut declarations = Vec::new();
+        let mut used = HashSet::new();
+        let mut variants = Vec::new();
+        for (block_uid, fields) in block_types {
+            let block_name = self.get_block_type_name(config, block_uid);
+            // The block metadata keeps its name, a block field of the same name gets a suffix
+            let mut field_names = HashSet::from([String::from("Metadata")]);
+            let mut members = self.generate_members(fields, &mut field_names);
+            members.push(format!(
+                "Metadata {} `json:\"_metadata\"`",
+                self.options.get_builtin_name("BlockMetadata")
+            ));
+            declarations.push(self.generate_struct("", &block_name, &members));
+            variants.push(format!(
+                "{} *{block_name} `json:\"{block_uid},omitempty\"`",
+                self.get_unique_field_name(block_uid, &mut used)
+            ));
+        }
+        let type_name = self.get_nested_type_name(config);
+        declarations.push(self.generate_struct(
+            &self.get_doc_comment(&[format!("{type_name} holds exactly one of its blocks")]),
+            &type_name,
+            &variants,
+        ));
+        declarations.join("\n")
+    }
+
+    /// Types declared for the nested fields of a definition, in field order
+    fn generate_nested_declarations(&self, fields: &[Field]) -> Vec<String> {
+        let mut declarations = Vec::new();
+        walk_fields(fields, &mut |field| match field {
+            Field::Text(config) => {
+                if let Some(choices) = config.enumeration.as_ref().filter(|c| !c.is_empty()) {
+                    let values: Vec<(String, String)> = choices
+                        .iter()
+                        .map(|choice| {
+                            (
+                                choice.clone(),
+                                serde_json::Value::from(choice.as_str()).to_string(),
+                            )
+                        })
+                        .collect();
+                    declarations.push(self.generate_select_declaration(config, "string", &values));
+                }
+            }
+            Field::Number(config) => {
+                let choices = self.get_number_choices(config);
+                if !choices.is_empty() {
+                    let values: Vec<(String, String)> = choices
+                        .into_iter()
+                        .map(|choice| (choice.clone(), choice.clone()))
+                        .collect();
+                    declarations.push(self.generate_select_declaration(config, "float64", &values));
+                }
+            }
+            Field::Group(config) if !config.fields.is_empty() => {
+                declarations.push(self.generate_group_declaration(config));
+            }
+            Field::Blocks(config) => {
+                if let Some(block_types) = config.block_types.as_ref().filter(|b| !b.is_empty()) {
+                    declarations.push(self.generate_blocks_declaration(config, block_types));
+                }
+            }
+            _ => {}
+        });
+        declarations
+    }
+
+    fn get_reference_builtin(&self) -> String {
+        let reference = self.options.get_builtin_name("Reference");
+        format!(
+            r#"// {reference} is a referenced entry, with Entry set when the request includes the reference
+type {reference}[T any] struct {{
+	UID            string `json:"uid"`
+	ContentTypeUID string `json:"_content_type_uid"`
+	Entry          *T     `json:"-"`
+}}
+
+func (r *{reference}[T]) UnmarshalJSON(data []byte) error {{
+	var stub struct {{
+		UID            string `json:"uid"`
+		ContentTypeUID string `json:"_content_type_uid"`
+	}}
+	if err := json.Unmarshal(data, &stub); err != nil {{
+		return err
+	}}
+	r.UID, r.ContentTypeUID = stub.UID, stub.ContentTypeUID
+
+	// Unresolved references only hold the uid and content type uid
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {{
+		return err
+	}}
+	if len(fields) > 2 {{
+		r.Entry = new(T)
+		return json.Unmarshal(data, r.Entry)
+	}}
+	return nil
+}}
+
+func (r {reference}[T]) MarshalJSON() ([]byte, error) {{
+	if r.Entry != nil {{
+		return json.Marshal(r.Entry)
+	}}
+	return json.Marshal(map[string]string{{"uid": r.UID, "_content_type_uid": r.ContentTypeUID}})
+}}
+"#
+        )
+    }
+}
+
+/// Checks that `value` can be used as the package clause of the generated file
+pub fn parse_package(value: &str) -> Result<String, String> {
+    let mut chars = value.chars();
+    let is_identifier = chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphabetic() || c.is_ascii_digit());
+    if !is_identifier || value == "_" || KEYWORDS.contains(&value) {
+        return Err(format!("`{value}` is not a valid Go package name"));
+    }
+    Ok(value.to_string())
+}
+
+impl Generator for GoGenerator {
+    fn generate_text(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_text_type(config), false)
+    }
+
+    fn generate_number(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_number_type(config), false)
+    }
+
+    fn generate_date(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, "string", false)
+    }
+
+    fn generate_bool(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, "bool", false)
+    }
+
+    fn generate_file(&self, config: &FieldConfig) -> String {
+        self.generate_property(
+            config,
+            &self.options.get_builtin_name("ContentstackFile"),
+            false,
+        )
+    }
+
+    fn generate_link(&self, config: &FieldConfig) -> String {
+        self.generate_property(
+            config,
+            &self.options.get_builtin_name("ContentstackLink"),
+            false,
+        )
+    }
+
+    fn generate_json(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_json_type(), true)
+    }
+
+    fn generate_reference(&self, config: &FieldConfig) -> String {
+        let (reference_type, nilable) = self.get_reference_type(config);
+        self.generate_property(config, &reference_type, nilable)
+    }
+
+    fn generate_global_field(&self, config: &FieldConfig) -> String {
+        let (global_type, nilable) = self.get_global_field_type(config);
+        self.generate_property(config, &global_type, nilable)
+    }
+
+    fn generate_blocks(&self, config: &FieldConfig) -> String {
+        self.generate_member(
+            config,
+            &format!("[]{}", self.get_blocks_type(config)),
+            true,
+            &mut HashSet::new(),
+        )
+    }
+
+    fn generate_group(&self, config: &FieldConfig) -> String {
+        let (group_type, nilable) = self.get_group_type(config);
+        self.generate_property(config, &group_type, nilable)
+    }
+
+    fn generate_interface(&self, config: &GenerateConfig) -> String {
+        // Groups, blocks and selects are declared right before the struct using them
+        let mut declarations = self.generate_nested_declarations(&config.fields);
+        declarations.push(self.generate_struct(
+            &self.get_definition_doc(config),
+            &self.options.get_type_name(&config.name),
+            &self.generate_members(&config.fields, &mut HashSet::new()),
+        ));
+        declarations.join("\n")
+    }
+
+    fn get_builtins(&self) -> String {
+        let name = |name: &str| self.options.get_builtin_name(name);
+        let member = |uid: &str, field_type: &str| {
+            let omitempty = if field_type.starts_with('*') {
+                ",omitempty"
+            } else {
+                ""
+            };
+            format!(
+                "{} {field_type} `json:\"{uid}{omitempty}\"`",
+                self.get_field_name(uid)
+            )
+        };
+
+        let builtins = [
+            self.generate_struct(
+                "",
+                &name("ContentstackFile"),
+                &[
+                    member("uid", "string"),
+                    member("created_at", "string"),
+                    member("updated_at", "string"),
+                    member("created_by", "string"),
+                    member("updated_by", "string"),
+                    member("content_type", "string"),
+                    member("file_size", "string"),
+                    member("tags", "[]string"),
+                    member("filename", "string"),
+                    member("url", "string"),
+                    member("ACL", "any"),
+                    member("is_dir", "bool"),
+                    member("parent_uid", "*string"),
+                    member("_version", "int"),
+                    member("title", "string"),
+                    member("_metadata", "map[string]any"),
+                    member("description", "*string"),
+                    member("dimension", &format!("*{}", name("ContentstackDimension"))),
+                    member("publish_details", &name("PublishDetails")),
+                ],
+            ),
+            self.generate_struct(
+                "",
+                &name("ContentstackDimension"),
+                &[member("height", "int"), member("width", "int")],
+            ),
+            self.generate_struct(
+                "",
+                &name("PublishDetails"),
+                &[
+                    member("environment", "string"),
+                    member("locale", "string"),
+                    member("time", "string"),
+                    member("user", "string"),
+                ],
+            ),
+            self.generate_struct(
+                "",
+                &name("ContentstackLink"),
+                &[member("title", "string"), member("href", "string")],
+            ),
+            format!("type {} = map[string]any\n", self.get_json_type()),
+            self.generate_struct("", &name("BlockMetadata"), &[member("uid", "string")]),
+            self.get_reference_builtin(),
+        ];
+        builtins.join("\n")
+    }
+
+    fn get_file_extension(&self) -> String {
+        String::from("go")
+    }
+
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut output = format!(
+            "// Code generated by cs-ts. DO NOT EDIT.\n\npackage {}\n\nimport \"encoding/json\"\n\n",
+            self.package
+        );
+        output.push_str(&self.get_builtins());
+        for definition in global_fields.iter().chain(content_types) {
+            output.push('\n');
+            output.push_str(&self.generate_interface(definition));
+        }
+        output
+    }
+}
@@ -0,0 +1,413 @@
+use crate::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, Nullability,
+};
+use serde_json::{Map, Value, json};
+
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// JSON Schema (draft 2020-12) of the content model, one `$defs` entry per definition
+pub struct JsonSchemaGenerator {
+    options: GeneratorOptions,
+}
+
+impl JsonSchemaGenerator {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self::with_options(GeneratorOptions::new(prefix, postfix))
+    }
+
+    pub fn with_options(options: GeneratorOptions) -> Self {
+        Self { options }
+    }
+
+    fn get_ref(&self, name: &str) -> Value {
+        json!({ "$ref": format!("#/$defs/{name}") })
+    }
+
+    /// Key of a builtin type such as `ContentstackFile` in `$defs`
+    fn get_builtin_ref(&self, name: &str) -> Value {
+        self.get_ref(&self.options.get_builtin_name(name))
+    }
+
+    fn get_union(&self, mut schemas: Vec<Value>) -> Value {
+        if schemas.len() == 1 {
+            schemas.remove(0)
+        } else {
+            json!({ "anyOf": schemas })
+        }
+    }
+
+    /// Property schema of a field: multiple values, nullability, title and description applied
+    fn get_property_schema(&self, config: &FieldConfig, schema: Value) -> Value {
+        let schema = if config.multiple {
+            json!({ "type": "array", "items": schema })
+        } else {
+            schema
+        };
+        self.annotate(config, schema)
+    }
+
+    /// Applies nullability and the field's title and description to a property schema
+    fn annotate(&self, config: &FieldConfig, mut schema: Value) -> Value {
+        let nullable = self.options.is_optional(config)
+            && !matches!(self.options.nullability, Nullability::Optional);
+        if nullable {
+            schema = json!({ "anyOf": [schema, { "type": "null" }] });
+        }
+
+        if let Value::Object(object) = &mut schema {
+            let annotations = [
+                ("title", config.display_name.as_ref()),
+                ("description", config.description.as_ref()),
+            ];
+            for (keyword, value) in annotations {
+                if let Some(value) = value.filter(|value| !value.trim().is_empty()) {
+                    object.insert(keyword.to_string(), Value::from(value.as_str()));
+                }
+            }
+        }
+        schema
+    }
+
+    /// Whether the field has to be present, `required` holds the uids of these
+    fn is_required(&self, config: &FieldConfig) -> bool {
+        !self.options.is_optional(config)
+            || matches!(self.options.nullability, Nullability::Nullable)
+    }
+
+    /// Object schema over the given fields plus any extra, always required, properties
+    fn get_object_schema(&self, fields: &[Field], extra: Vec<(String, Value)>) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in fields {
+            let config = field.config();
+            properties.insert(config.name.clone(), self.get_field_schema(field));
+            if self.is_required(config) {
+                required.push(Value::from(config.name.as_str()));
+            }
+        }
+        for (name, schema) in extra {
+            required.push(Value::from(name.as_str()));
+            properties.insert(name, schema);
+        }
+
+        let mut schema = Map::new();
+        schema.insert(String::from("type"), Value::from("object"));
+        schema.insert(String::from("properties"), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert(String::from("required"), Value::Array(required));
+        }
+        Value::Object(schema)
+    }
+
+    fn get_field_schema(&self, field: &Field) -> Value {
+        match field {
+            Field::Text(config) => self.get_text_schema(config),
+            Field::Number(config) => self.get_number_schema(config),
+            Field::Date(config) => self.get_date_schema(config),
+            Field::Bool(config) => self.get_property_schema(config, json!({ "type": "boolean" })),
+            Field::File(config) => self.get_builtin_schema(config, "ContentstackFile"),
+            Field::Link(config) => self.get_builtin_schema(config, "ContentstackLink"),
+            Field::Json(config) => self.get_builtin_schema(config, "ContentstackJson"),
+            Field::Reference(config) => self.get_reference_schema(config),
+            Field::Global(config) => self.get_global_field_schema(config),
+            Field::Blocks(config) => self.get_blocks_schema(config),
+            Field::Group(config) => self.get_group_schema(config),
+        }
+    }
+
+    fn get_date_schema(&self, config: &FieldConfig) -> Value {
+        self.get_property_schema(config, json!({ "type": "string", "format": "date-time" }))
+    }
+
+    fn get_builtin_schema(&self, config: &FieldConfig, name: &str) -> Value {
+        self.get_property_schema(config, self.get_builtin_ref(name))
+    }
+
+    fn get_text_schema(&self, config: &FieldConfig) -> Value {
+        let schema = match &config.enumeration {
+            Some(choices) => json!({ "enum": choices }),
+            None => json!({ "type": "string" }),
+        };
+        self.get_property_schema(config, schema)
+    }
+
+    fn get_number_schema(&self, config: &FieldConfig) -> Value {
+        let schema = match &config.enumeration {
+            Some(choices) => {
+                let choices: Vec<Value> = choices
+                    .iter()
+                    .map(|choice| {
+                        serde_json::from_str::<serde_json::Number>(choice)
+                            .map(Value::Number)
+                            .unwrap_or_else(|_| Value::from(choice.as_str()))
+                    })
+                    .collect();
+                json!({ "enum": choices })
+            }
+            None => json!({ "type": "number" }),
+        };
+        self.get_property_schema(config, schema)
+    }
+
+    fn get_reference_schema(&self, config: &FieldConfig) -> Value {
+        let schemas = config
+            .reference_to
+            .iter()
+            .flat_map(|uid| {
+                let stub = json!({
+                    "type": "object",
+                    "properties": {
+                        "uid": { "type": "string" },
+                        "_content_type_uid": { "const": uid },
+                    },
+                    "required": ["uid", "_content_type_uid"],
+                });
+                [self.get_ref(uid), stub]
+            })
+            .collect::<Vec<Value>>();
+        let schema = if schemas.is_empty() {
+            json!({})
+        } else {
+            self.get_union(schemas)
+        };
+        self.get_property_schema(config, schema)
+    }
+
+    fn get_global_field_schema(&self, config: &FieldConfig) -> Value {
+        let schemas = config
+            .reference_to
+            .iter()
+            .map(|uid| self.get_ref(uid))
+            .collect::<Vec<Value>>();
+        let schema = if schemas.is_empty() {
+            json!({})
+        } else {
+            self.get_union(schemas)
+        };
+        self.get_property_schema(config, schema)
+    }
+
+    fn get_blocks_schema(&self, config: &FieldConfig) -> Value {
+        // Every item holds exactly one block, keyed by the block uid
+        let variants = config
+            .block_types
+            .iter()
+            .flatten()
+            .map(|(block_uid, fields)| {
+                let metadata = json!({
+                    "type": "object",
+                    "properties": { "uid": { "type": "string" } },
+                    "required": ["uid"],
+                });
+                let block =
+                    self.get_object_schema(fields, vec![(String::from("_metadata"), metadata)]);
+                let mut variant = self.get_object_schema(&[], vec![(block_uid.clone(), block)]);
+                variant["additionalProperties"] = Value::Bool(false);
+                variant
+            })
+            .collect::<Vec<Value>>();
+        let items = if variants.is_empty() {
+            json!({})
+        } else {
+            self.get_union(variants)
+        };
+
+        // Blocks are always a list, whatever `multiple` says
+        self.annotate(config, json!({ "type": "array", "items": items }))
+    }
+
+    fn get_group_schema(&self, config: &FieldConfig) -> Value {
+        let schema = if config.fields.is_empty() {
+            json!({ "type": "object" })
+        } else {
+            self.get_object_schema(&config.fields, Vec::new())
+        };
+        self.get_property_schema(config, schema)
+    }
+
+    fn get_definition_schema(&self, config: &GenerateConfig, is_content_type: bool) -> Value {
+        let mut schema = self.get_object_schema(&config.fields, Vec::new());
+        // Only present on entries fetched with their content type, so never required
+        if is_content_type {
+            schema["properties"]["_content_type_uid"] = json!({ "const": config.name });
+        }
+
+        let mut definition = Map::new();
+        for (keyword, value) in [
+            ("title", &config.title),
+            ("description", &config.description),
+        ] {
+            if let Some(value) = value.as_ref().filter(|value| !value.trim().is_empty()) {
+                definition.insert(keyword.to_string(), Value::from(value.as_str()));
+            }
+        }
+        if let Value::Object(object) = schema {
+            definition.extend(object);
+        }
+        Value::Object(definition)
+    }
+
+    fn get_builtin_schemas(&self) -> Vec<(String, Value)> {
+        let string = || json!({ "type": "string" });
+        let date = || json!({ "type": "string", "format": "date-time" });
+        let object = |properties: Value, required: &[&str]| json!({ "type": "object", "properties": properties, "required": required });
+
+        let publish_details = self.options.get_builtin_name("PublishDetails");
+        vec![
+            (
+                self.options.get_builtin_name("ContentstackFile"),
+                object(
+                    json!({
+                        "uid": string(),
+                        "created_at": date(),
+                        "updated_at": date(),
+                        "created_by": string(),
+                        "updated_by": string(),
+                        "content_type": string(),
+                        "file_size": string(),
+                        "tags": { "type": "array", "items": string() },
+                        "filename": string(),
+                        "url": string(),
+                        "ACL": {},
+                        "is_dir": { "type": "boolean" },
+                        "parent_uid": { "type": ["string", "null"] },
+                        "_version": { "type": "number" },
+                        "title": string(),
+                        "_metadata": { "type": "object" },
+                        "description": string(),
+                        "dimension": object(
+                            json!({
+                                "height": { "type": "number" },
+                                "width": { "type": "number" },
+                            }),
+                            &["height", "width"],
+                        ),
+                        "publish_details": self.get_ref(&publish_details),
+                    }),
+                    &["uid", "filename", "url", "content_type"],
+                ),
+            ),
+            (
+                publish_details.clone(),
+                object(
+                    json!({
+                        "environment": string(),
+                        "locale": string(),
+                        "time": date(),
+                        "user": string(),
+                    }),
+                    &["environment", "locale", "time", "user"],
+                ),
+            ),
+            (
+                self.options.get_builtin_name("ContentstackLink"),
+                object(
+                    json!({ "title": string(), "href": string() }),
+                    &["title", "href"],
+                ),
+            ),
+            (
+                self.options.get_builtin_name("ContentstackJson"),
+                json!({ "type": "object" }),
+            ),
+        ]
+    }
+}
+
+impl Generator for JsonSchemaGenerator {
+    fn generate_text(&self, config: &FieldConfig) -> String {
+        self.get_text_schema(config).to_string()
+    }
+
+    fn generate_number(&self, config: &FieldConfig) -> String {
+        self.get_number_schema(config).to_string()
+    }
+
+    fn generate_date(&self, config: &FieldConfig) -> String {
+        self.get_date_schema(config).to_string()
+    }
+
+    fn generate_bool(&self, config: &FieldConfig) -> String {
+        self.get_property_schema(config, json!({ "type": "boolean" }))
+            .to_string()
+    }
+
+    fn generate_file(&self, config: &FieldConfig) -> String {
+        self.get_builtin_schema(config, "ContentstackFile")
+            .to_string()
+    }
+
+    fn generate_link(&self, config: &FieldConfig) -> String {
+        self.get_builtin_schema(config, "ContentstackLink")
+            .to_string()
+    }
+
+    fn generate_json(&self, config: &FieldConfig) -> String {
+        self.get_builtin_schema(config, "ContentstackJson")
+            .to_string()
+    }
+
+    fn generate_reference(&self, config: &FieldConfig) -> String {
+        self.get_reference_schema(config).to_string()
+    }
+
+    fn generate_global_field(&self, config: &FieldConfig) -> String {
+        self.get_global_field_schema(config).to_string()
+    }
+
+    fn generate_blocks(&self, config: &FieldConfig) -> String {
+        self.get_blocks_schema(config).to_string()
+    }
+
+    fn generate_group(&self, config: &FieldConfig) -> String {
+        self.get_group_schema(config).to_string()
+    }
+
+    fn generate_interface(&self, config: &GenerateConfig) -> String {
+        self.get_definition_schema(config, true).to_string()
+    }
+
+    fn get_builtins(&self) -> String {
+        Value::Object(self.get_builtin_schemas().into_iter().collect()).to_string()
+    }
+
+    fn get_file_extension(&self) -> String {
+        String::from("schema.json")
+    }
+
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut definitions: Map<String, Value> = self.get_builtin_schemas().into_iter().collect();
+        for definition in global_fields {
+            definitions.insert(
+                definition.name.clone(),
+                self.get_definition_schema(definition, false),
+            );
+        }
+        for definition in content_types {
+            definitions.insert(
+                definition.name.clone(),
+                self.get_definition_schema(definition, true),
+            );
+        }
+
+        // The document itself accepts an entry of any content type
+        let entries = content_types
+            .iter()
+            .map(|content_type| self.get_ref(&content_type.name))
+            .collect::<Vec<Value>>();
+        let mut document = Map::new();
+        document.insert(String::from("$schema"), Value::from(DIALECT));
+        if !entries.is_empty() {
+            document.insert(String::from("anyOf"), Value::Array(entries));
+        }
+        document.insert(String::from("$defs"), Value::Object(definitions));
+
+        let mut output = serde_json::to_string_pretty(&document).unwrap_or_default();
+        output.push('\n');
+        output
+    }
+}
@@ -1,19 +1,32 @@
+use crate::error::Result;
+use clap::ValueEnum;
+
 #[derive(Debug, Default)]
 pub struct FieldConfig {
     pub name: String,
+    pub display_name: Option<String>,
     pub optional: bool,
     pub multiple: bool,
     pub description: Option<String>,
+    /// Help text shown to editors below the field
+    pub instruction: Option<String>,
     pub enumeration: Option<Vec<String>>,
     pub reference_to: Vec<String>,
     pub fields: Vec<Field>,
     pub block_types: Option<Vec<(String, Vec<Field>)>>,
+    /// Uids leading to this field, starting with the owning content type or global field
+    pub path: Vec<String>,
+    /// Whether this is the `title` of a content type, which every entry has
+    pub entry_title: bool,
 }
 
 #[derive(Debug)]
 pub struct GenerateConfig {
     pub name: String,
+    pub title: Option<String>,
     pub description: Option<String>,
+    /// Link to the content type or global field in the CMS editor
+    pub editor_url: Option<String>,
     pub fields: Vec<Field>,
 }
 
@@ -32,7 +45,202 @@ pub enum Field {
     Group(FieldConfig),
 }
 
-pub trait Generator {
+impl Field {
+    pub fn config(&self) -> &FieldConfig {
+        match self {
+            Field::Text(config)
+            | Field::Number(config)
+            | Field::Date(config)
+            | Field::Bool(config)
+            | Field::File(config)
+            | Field::Link(config)
+            | Field::Json(config)
+            | Field::Reference(config)
+            | Field::Global(config)
+            | Field::Blocks(config)
+            | Field::Group(config) => config,
+        }
+    }
+
+    pub fn config_mut(&mut self) -> &mut FieldConfig {
+        match self {
+            Field::Text(config)
+            | Field::Number(config)
+            | Field::Date(config)
+            | Field::Bool(config)
+            | Field::File(config)
+            | Field::Link(config)
+            | Field::Json(config)
+            | Field::Reference(config)
+            | Field::Global(config)
+            | Field::Blocks(config)
+            | Field::Group(config) => config,
+        }
+    }
+}
+
+/// Visits every field, including the nested fields of groups and modular blocks
+pub fn walk_fields<'a>(fields: &'a [Field], visit: &mut impl FnMut(&'a Field)) {
+    for field in fields {
+        visit(field);
+        let config = field.config();
+        walk_fields(&config.fields, visit);
+        for (_, block_fields) in config.block_types.iter().flatten() {
+            walk_fields(block_fields, visit);
+        }
+    }
+}
+
+/// Sets `path` on every field, including nested ones, relative to `parent`
+pub fn assign_paths(fields: &mut [Field], parent: &[String]) {
+    for field in fields {
+        let config = field.config_mut();
+        let mut path = parent.to_vec();
+        path.push(config.name.clone());
+
+        assign_paths(&mut config.fields, &path);
+        for (block_uid, block_fields) in config.block_types.iter_mut().flatten() {
+            let mut block_path = path.clone();
+            block_path.push(block_uid.clone());
+            assign_paths(block_fields, &block_path);
+        }
+
+        config.path = path;
+    }
+}
+
+/// How optional fields are represented
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum Nullability {
+    /// Optional fields may be missing, e.g. `field?: T`
+    #[default]
+    Optional,
+    /// Optional fields are present but may be null, e.g. `field: T | null`
+    Nullable,
+    /// Optional fields may be missing or null, e.g. `field?: T | null`
+    OptionalNullable,
+}
+
+/// Options every backend honours
+#[derive(Debug, Default, Clone)]
+pub struct GeneratorOptions {
+    /// Prepended to every generated type name
+    pub prefix: String,
+    /// Appended to every generated type name
+    pub postfix: String,
+    /// Whether prefix/postfix also apply to builtin types such as `ContentstackFile`
+    pub affix_builtins: bool,
+    /// How optional fields are represented
+    pub nullability: Nullability,
+    /// Treat every field except the entry title as optional, regardless of `mandatory`
+    pub assume_empty: bool,
+}
+
+impl GeneratorOptions {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self {
+            prefix: prefix.unwrap_or("").to_string(),
+            postfix: postfix.unwrap_or("").to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Name of the type generated for a content type or global field uid
+    pub fn get_type_name(&self, name: &str) -> String {
+        format!("{}{}{}", self.prefix, to_pascal_case(name), self.postfix)
+    }
+
+    /// Name of a builtin type such as `ContentstackFile`
+    pub fn get_builtin_name(&self, name: &str) -> String {
+        if self.affix_builtins {
+            format!("{}{name}{}", self.prefix, self.postfix)
+        } else {
+            name.to_string()
+        }
+    }
+
+    pub fn is_optional(&self, config: &FieldConfig) -> bool {
+        // The entry title is the only field Contentstack always fills
+        config.optional || (self.assume_empty && !config.entry_title)
+    }
+}
+
+/// Converts a uid in any case to PascalCase
+pub fn to_pascal_case(s: &str) -> String {
+    s.split(&['-', '_', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => {
+                    first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
+                }
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Heading of a doc comment: the display name followed by the uid path
+pub(crate) fn get_doc_heading(display_name: Option<&str>, path: &str) -> String {
+    let display_name = display_name.map(str::trim).unwrap_or_default();
+    match (display_name.is_empty(), path.is_empty()) {
+        (_, true) => display_name.to_string(),
+        (true, false) => format!("`{path}`"),
+        (false, false) => format!("{display_name} (`{path}`)"),
+    }
+}
+
+/// Heading, description and instruction of a field, empty when there is nothing to say
+pub(crate) fn get_field_doc_paragraphs(config: &FieldConfig) -> Vec<String> {
+    let display_name = config.display_name.as_deref().unwrap_or_default();
+    let description = config.description.clone().unwrap_or_default();
+    let instruction = config.instruction.clone().unwrap_or_default();
+    // A bare uid path adds nothing worth a comment
+    if display_name.trim().is_empty()
+        && description.trim().is_empty()
+        && instruction.trim().is_empty()
+    {
+        return Vec::new();
+    }
+
+    vec![
+        get_doc_heading(Some(display_name), &config.path.join(".")),
+        description,
+        instruction,
+    ]
+}
+
+/// Heading and description of a content type or global field
+pub(crate) fn get_definition_doc_paragraphs(config: &GenerateConfig) -> Vec<String> {
+    let heading = match &config.title {
+        Some(_) => get_doc_heading(config.title.as_deref(), &config.name),
+        None => String::new(),
+    };
+    vec![heading, config.description.clone().unwrap_or_default()]
+}
+
+/// Comment with `marker` in front of every line, paragraphs separated by a bare marker;
+/// empty when there is nothing to say
+pub(crate) fn get_line_comment(marker: &str, paragraphs: &[String]) -> String {
+    let mut comment = String::new();
+    for paragraph in paragraphs.iter().filter(|p| !p.trim().is_empty()) {
+        if !comment.is_empty() {
+            comment.push_str(&format!("{marker}\n"));
+        }
+        for line in paragraph.trim().lines().map(str::trim_end) {
+            if line.is_empty() {
+                comment.push_str(&format!("{marker}\n"));
+            } else {
+                comment.push_str(&format!("{marker} {line}\n"));
+            }
+        }
+    }
+    comment
+}
+
+pub trait Generator: Sync {
     fn generate_text(&self, config: &FieldConfig) -> String;
     fn generate_number(&self, config: &FieldConfig) -> String;
     fn generate_date(&self, config: &FieldConfig) -> String;
@@ -46,7 +254,130 @@ pub trait Generator {
     fn generate_group(&self, config: &FieldConfig) -> String;
     fn generate_interface(&self, config: &GenerateConfig) -> String;
     fn get_builtins(&self) -> String;
+
+    /// Extension of generated files, without the leading dot
+    fn get_file_extension(&self) -> String;
+
+    fn generate_field(&self, field: &Field) -> String {
+        match field {
+            Field::Text(config) => self.generate_text(config),
+            Field::Number(config) => self.generate_number(config),
+            Field::Date(config) => self.generate_date(config),
+            Field::Bool(config) => self.generate_bool(config),
+            Field::File(config) => self.generate_file(config),
+            Field::Link(config) => self.generate_link(config),
+            Field::Json(config) => self.generate_json(config),
+            Field::Reference(config) => self.generate_reference(config),
+            Field::Global(config) => self.generate_global_field(config),
+            Field::Blocks(config) => self.generate_blocks(config),
+            Field::Group(config) => self.generate_group(config),
+        }
+    }
+
+    /// Single-file output: builtins, then global fields, then content types
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut output = self.get_builtins();
+        output.push('\n');
+        for definition in global_fields.iter().chain(content_types) {
+            output.push_str(&self.generate_interface(definition));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Fails when two declarations of the output would share a name
+    fn check_names(
+        &self,
+        _global_fields: &[GenerateConfig],
+        _content_types: &[GenerateConfig],
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Multi-file output as `(file name, content)` pairs, `None` when only single-file output exists
+    fn generate_modules(
+        &self,
+        _global_fields: &[GenerateConfig],
+        _content_types: &[GenerateConfig],
+    ) -> Option<Vec<(String, String)>> {
+        None
+    }
 }
 
+pub mod go;
+pub mod json_schema;
+pub mod python;
+pub mod rust;
 pub mod typescript;
-pub use typescript::TypescriptGenerator;
+pub mod zod;
+pub use go::GoGenerator;
+pub use json_schema::JsonSchemaGenerator;
+pub use python::{PythonGenerator, PythonStyle};
+pub use rust::RustGenerator;
+pub use typescript::{
+    Ambient, BuiltinsMode, DateType, DeclarationStyle, Formatting, Indent, QuoteStyle,
+    ReferenceStyle, SelectStyle, TypescriptGenerator, TypescriptOptions,
+};
+pub use zod::ZodGenerator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_pascal_case_snake_case() {
+        assert_eq!(to_pascal_case("snake_case"), "SnakeCase");
+        assert_eq!(to_pascal_case("user_profile"), "UserProfile");
+        assert_eq!(to_pascal_case("api_key_config"), "ApiKeyConfig");
+    }
+
+    #[test]
+    fn test_to_pascal_case_kebab_case() {
+        assert_eq!(to_pascal_case("kebab-case"), "KebabCase");
+        assert_eq!(to_pascal_case("user-profile"), "UserProfile");
+        assert_eq!(to_pascal_case("api-key-config"), "ApiKeyConfig");
+    }
+
+    #[test]
+    fn test_to_pascal_case_space_separated() {
+        assert_eq!(to_pascal_case("space case"), "SpaceCase");
+        assert_eq!(to_pascal_case("user profile"), "UserProfile");
+        assert_eq!(to_pascal_case("api key config"), "ApiKeyConfig");
+    }
+
+    #[test]
+    fn test_to_pascal_case_mixed_separators() {
+        assert_eq!(to_pascal_case("mixed-case_test"), "MixedCaseTest");
+        assert_eq!(to_pascal_case("api-key_config test"), "ApiKeyConfigTest");
+    }
+
+    #[test]
+    fn test_to_pascal_case_single_word() {
+        assert_eq!(to_pascal_case("user"), "User");
+        assert_eq!(to_pascal_case("test"), "Test");
+        assert_eq!(to_pascal_case("API"), "Api");
+    }
+
+    #[test]
+    fn test_to_pascal_case_empty_string() {
+        assert_eq!(to_pascal_case(""), "");
+    }
+
+    #[test]
+    fn test_to_pascal_case_multiple_separators() {
+        assert_eq!(to_pascal_case("test__case"), "TestCase");
+        assert_eq!(to_pascal_case("test--case"), "TestCase");
+        assert_eq!(to_pascal_case("test  case"), "TestCase");
+    }
+
+    #[test]
+    fn test_to_pascal_case_leading_trailing_separators() {
+        assert_eq!(to_pascal_case("_test_case_"), "TestCase");
+        assert_eq!(to_pascal_case("-test-case-"), "TestCase");
+        assert_eq!(to_pascal_case(" test case "), "TestCase");
+    }
+}
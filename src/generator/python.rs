@@ -0,0 +1,731 @@
+use crate::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, Nullability,
+    get_definition_doc_paragraphs, get_field_doc_paragraphs, get_line_comment,
+};
+use clap::ValueEnum;
+use std::collections::HashSet;
+
+/// Python keywords, which cannot be attribute names
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Names a Pydantic field must not take: `BaseModel` attributes, and names used in annotations
+/// that a field with a default would shadow for the fields after it
+const RESERVED_ATTRIBUTES: &[&str] = &[
+    "construct",
+    "copy",
+    "dict",
+    "from_orm",
+    "json",
+    "parse_file",
+    "parse_obj",
+    "parse_raw",
+    "schema",
+    "schema_json",
+    "update_forward_refs",
+    "validate",
+    "Annotated",
+    "Any",
+    "Field",
+    "Literal",
+    "Optional",
+    "Union",
+    "bool",
+    "float",
+    "int",
+    "list",
+    "str",
+];
+
+/// Kind of Python classes generated
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum PythonStyle {
+    /// Pydantic v2 models that validate and parse entries
+    #[default]
+    Pydantic,
+    /// `TypedDict`s describing the raw JSON, without runtime validation
+    TypedDict,
+}
+
+/// A class member, rendered as an attribute or as a key of a functional `TypedDict`
+struct Member {
+    key: String,
+    annotation: String,
+    /// Python expression the attribute defaults to, `None` for optional members
+    default: Option<String>,
+    docs: String,
+}
+
+/// Python classes for Delivery API responses, as Pydantic models or `TypedDict`s
+pub struct PythonGenerator {
+    options: GeneratorOptions,
+    style: PythonStyle,
+}
+
+impl PythonGenerator {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self::with_options(
+            GeneratorOptions::new(prefix, postfix),
+            PythonStyle::default(),
+        )
+    }
+
+    pub fn with_options(options: GeneratorOptions, style: PythonStyle) -> Self {
+        Self { options, style }
+    }
+
+    fn is_pydantic(&self) -> bool {
+        matches!(self.style, PythonStyle::Pydantic)
+    }
+
+    /// Indents every non-empty line of `code` by four spaces
+    fn indent(&self, code: &str) -> String {
+        let mut output = String::new();
+        for line in code.lines() {
+            if !line.is_empty() {
+                output.push_str("    ");
+                output.push_str(line);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Double-quoted string literal
+    fn to_string_literal(&self, value: &str) -> String {
+        serde_json::Value::from(value).to_string()
+    }
+
+    fn is_identifier(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let starts_ok = chars
+            .next()
+            .is_some_and(|first| first == '_' || first.is_ascii_alphabetic());
+        starts_ok
+            && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+            && !KEYWORDS.contains(&name)
+    }
+
+    /// `#` comment lines from paragraphs, empty when there is nothing to say
+    fn get_comment(&self, paragraphs: &[String]) -> String {
+        get_line_comment("#", paragraphs)
+    }
+
+    /// Docstring from paragraphs, empty when there is nothing to say
+    fn get_docstring(&self, paragraphs: &[String]) -> String {
+        let paragraphs: Vec<String> = paragraphs
+            .iter()
+            .map(|p| {
+                p.trim()
+                    .replace('\\', "\\\\")
+                    .replace("\"\"\"", "\\\"\\\"\\\"")
+            })
+            .filter(|p| !p.is_empty())
+            .collect();
+        match paragraphs.as_slice() {
+            [] => String::new(),
+            // A closing quote right before `"""` would end the string early
+            [paragraph] if !paragraph.contains('\n') && !paragraph.ends_with('"') => {
+                format!("\"\"\"{paragraph}\"\"\"\n")
+            }
+            _ => format!("\"\"\"\n{}\n\"\"\"\n", paragraphs.join("\n\n")),
+        }
+    }
+
+    fn get_field_comment(&self, config: &FieldConfig) -> String {
+        self.get_comment(&get_field_doc_paragraphs(config))
+    }
+
+    fn get_definition_paragraphs(&self, config: &GenerateConfig) -> Vec<String> {
+        let mut paragraphs = get_definition_doc_paragraphs(config);
+        paragraphs.extend(config.editor_url.clone());
+        paragraphs
+    }
+
+    /// Snake-case attribute name for a uid, `None` when the uid can be used as it is
+    fn get_attribute_name(&self, uid: &str) -> Option<String> {
+        // Pydantic treats attributes starting with an underscore as private
+        let usable = self.is_identifier(uid)
+            && !uid.starts_with('_')
+            && !uid.starts_with("model_")
+            && !RESERVED_ATTRIBUTES.contains(&uid);
+        if usable {
+            return None;
+        }
+
+        let name: String = uid
+            .trim_start_matches('_')
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let name = match name.chars().next() {
+            Some(first) if first.is_ascii_alphabetic() => name,
+            Some(_) => format!("field_{name}"),
+            None => String::from("field"),
+        };
+        if KEYWORDS.contains(&name.as_str())
+            || RESERVED_ATTRIBUTES.contains(&name.as_str())
+            || name.starts_with("model_")
+        {
+            Some(format!("{name}_"))
+        } else {
+            Some(name)
+        }
+    }
+
+    /// Uid path of a field joined with `_`, the base of the names of its nested types
+    fn get_nested_name(&self, config: &FieldConfig) -> String {
+        if config.path.is_empty() {
+            config.name.clone()
+        } else {
+            config.path.join("_")
+        }
+    }
+
+    /// Name of the class declared for a group or a block
+    fn get_nested_type_name(&self, config: &FieldConfig, suffix: &[&str]) -> String {
+        let mut name = self.get_nested_name(config);
+        for part in suffix {
+            name.push('_');
+            name.push_str(part);
+        }
+        self.options.get_type_name(&name)
+    }
+
+    /// Quoted name of a content type or global field, which may be declared further down
+    fn get_forward_reference(&self, uid: &str) -> String {
+        self.to_string_literal(&self.options.get_type_name(uid))
+    }
+
+    fn get_json_type(&self) -> String {
+        self.options.get_builtin_name("ContentstackJson")
+    }
+
+    fn get_union(&self, types: &[String]) -> String {
+        match types {
+            [single] => single.clone(),
+            _ => format!("Union[{}]", types.join(", ")),
+        }
+    }
+
+    fn get_text_type(&self, config: &FieldConfig) -> String {
+        match &config.enumeration {
+            Some(choices) if !choices.is_empty() => format!(
+                "Literal[{}]",
+                choices
+                    .iter()
+                    .map(|choice| self.to_string_literal(choice))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => String::from("str"),
+        }
+    }
+
+    fn get_number_type(&self, config: &FieldConfig) -> String {
+        // `Literal` only takes integers, so other number selects stay floats
+        match &config.enumeration {
+            Some(choices)
+                if !choices.is_empty()
+                    && choices.iter().all(|choice| choice.parse::<i64>().is_ok()) =>
+            {
+                format!("Literal[{}]", choices.join(", "))
+            }
+            _ => String::from("float"),
+        }
+    }
+
+    fn get_reference_type(&self, config: &FieldConfig) -> String {
+        if config.reference_to.is_empty() {
+            return self.get_json_type();
+        }
+        let mut types: Vec<String> = config
+            .reference_to
+            .iter()
+            .map(|uid| self.get_forward_reference(uid))
+            .collect();
+        types.push(self.options.get_builtin_name("ContentstackReference"));
+        let union = self.get_union(&types);
+        if !self.is_pydantic() {
+            return union;
+        }
+        // Entries are tried in order, with the stub last so a resolved entry keeps its fields
+        format!("Annotated[{union}, Field(union_mode=\"left_to_right\")]")
+    }
+
+    fn get_global_field_type(&self, config: &FieldConfig) -> String {
+        match config.reference_to.as_slice() {
+            [uid] => self.get_forward_reference(uid),
+            _ => self.get_json_type(),
+        }
+    }
+
+    fn get_blocks_type(&self, config: &FieldConfig) -> String {
+        match &config.block_types {
+            Some(block_types) if !block_types.is_empty() => self.get_union(
+                &block_types
+                    .iter()
+                    .map(|(block_uid, _)| self.get_nested_type_name(config, &[block_uid, "block"]))
+                    .collect::<Vec<String>>(),
+            ),
+            _ => self.get_json_type(),
+        }
+    }
+
+    fn get_group_type(&self, config: &FieldConfig) -> String {
+        if config.fields.is_empty() {
+            self.get_json_type()
+        } else {
+            self.get_nested_type_name(config, &[])
+        }
+    }
+
+    /// Wraps an optional annotation: `Optional` for Pydantic, `NotRequired` and/or `Optional`
+    /// for `TypedDict` as configured
+    fn get_optional_annotation(&self, annotation: &str) -> String {
+        if self.is_pydantic() {
+            return format!("Optional[{annotation}]");
+        }
+        match self.options.nullability {
+            Nullability::Optional => format!("NotRequired[{annotation}]"),
+            Nullability::Nullable => format!("Optional[{annotation}]"),
+            Nullability::OptionalNullable => format!("NotRequired[Optional[{annotation}]]"),
+        }
+    }
+
+    fn get_member(&self, config: &FieldConfig, base_type: &str, multiple: bool) -> Member {
+        let annotation = if multiple {
+            format!("list[{base_type}]")
+        } else {
+            base_type.to_string()
+        };
+        let optional = self.options.is_optional(config);
+        Member {
+            key: config.name.clone(),
+            annotation: if optional {
+                self.get_optional_annotation(&annotation)
+            } else {
+                annotation
+            },
+            default: optional.then(|| String::from("None")),
+            docs: self.get_field_comment(config),
+        }
+    }
+
+    /// Member of a field, whichever kind it is
+    fn get_field_member(&self, field: &Field) -> Member {
+        match field {
+            Field::Text(config) => {
+                self.get_member(config, &self.get_text_type(config), config.multiple)
+            }
+            Field::Number(config) => {
+                self.get_member(config, &self.get_number_type(config), config.multiple)
+            }
+            Field::Date(config) => self.get_member(config, "str", config.multiple),
+            Field::Bool(config) => self.get_member(config, "bool", config.multiple),
+            Field::File(config) => self.get_member(
+                config,
+                &self.options.get_builtin_name("ContentstackFile"),
+                config.multiple,
+            ),
+            Field::Link(config) => self.get_member(
+                config,
+                &self.options.get_builtin_name("ContentstackLink"),
+                config.multiple,
+            ),
+            Field::Json(config) => self.get_member(config, &self.get_json_type(), config.multiple),
+            Field::Reference(config) => {
+                self.get_member(config, &self.get_reference_type(config), config.multiple)
+            }
+            Field::Global(config) => {
+                self.get_member(config, &self.get_global_field_type(config), config.multiple)
+            }
+            Field::Blocks(config) => self.get_member(config, &self.get_blocks_type(config), true),
+            Field::Group(config) => {
+                self.get_member(config, &self.get_group_type(config), config.multiple)
+            }
+        }
+    }
+
+    /// Member of a builtin class
+    fn get_builtin_member(&self, key: &str, annotation: &str, optional: bool) -> Member {
+        Member {
+            key: key.to_string(),
+            annotation: if optional {
+                self.get_optional_annotation(annotation)
+            } else {
+                annotation.to_string()
+            },
+            default: optional.then(|| String::from("None")),
+            docs: String::new(),
+        }
+    }
+
+    /// Class attribute, with an alias when the key cannot be used as the attribute name
+    fn render_member(&self, member: &Member) -> String {
+        self.render_named_member(member, self.get_attribute_name(&member.key))
+    }
+
+    /// Class attribute named `name`, or after its key when there is no name
+    fn render_named_member(&self, member: &Member, name: Option<String>) -> String {
+        let Member {
+            key,
+            annotation,
+            default,
+            docs,
+        } = member;
+        if !self.is_pydantic() {
+            return format!("{docs}{key}: {annotation}");
+        }
+
+        match (name, default) {
+            (Some(name), Some(default)) => format!(
+                "{docs}{name}: {annotation} = Field(default={default}, alias={})",
+                self.to_string_literal(key)
+            ),
+            (Some(name), None) => format!(
+                "{docs}{name}: {annotation} = Field(alias={})",
+                self.to_string_literal(key)
+            ),
+            (None, Some(default)) => format!("{docs}{key}: {annotation} = {default}"),
+            (None, None) => format!("{docs}{key}: {annotation}"),
+        }
+    }
+
+    /// Class declaration; a `TypedDict` whose keys are not all identifiers uses the functional syntax
+    fn generate_class(&self, name: &str, paragraphs: &[String], members: &[Member]) -> String {
+        if !self.is_pydantic() && !members.iter().all(|member| self.is_identifier(&member.key)) {
+            let entries: Vec<String> = members
+                .iter()
+                .map(|member| {
+                    format!(
+                        "{}{}: {},",
+                        member.docs,
+                        self.to_string_literal(&member.key),
+                        member.annotation
+                    )
+                })
+                .collect();
+            return format!(
+                "{}{name} = TypedDict(\n    {},\n    {{\n{}    }},\n)\n",
+                self.get_comment(paragraphs),
+                self.to_string_literal(name),
+                self.indent(&self.indent(&entries.join("\n")))
+            );
+        }
+
+        let base = if self.is_pydantic() {
+            self.options.get_builtin_name("ContentstackModel")
+        } else {
+            String::from("TypedDict")
+        };
+        let mut body = self.get_docstring(paragraphs);
+        let members: Vec<String> = members
+            .iter()
+            .zip(self.get_attribute_names(members))
+            .map(|(member, name)| self.render_named_member(member, name))
+            .collect();
+        if !members.is_empty() {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&members.join("\n"));
+        }
+        if body.is_empty() {
+            body.push_str("pass");
+        }
+        format!("class {name}({base}):\n{}", self.indent(&body))
+    }
+
+    /// Attribute name of each member, as `get_attribute_name` gives it, with a suffix where two
+    /// members would share one; system keys starting with `_` keep theirs
+    fn get_attribute_names(&self, members: &[Member]) -> Vec<Option<String>> {
+        if !self.is_pydantic() {
+            return members.iter().map(|_| None).collect();
+        }
+
+        let mut used = HashSet::new();
+        let mut names: Vec<Option<String>> = vec![None; members.len()];
+        let (system, fields): (Vec<usize>, Vec<usize>) =
+            (0..members.len()).partition(|&index| members[index].key.starts_with('_'));
+        for index in system.into_iter().chain(fields) {
+            let key = &members[index].key;
+            let name = self.get_attribute_name(key);
+            let base = name.clone().unwrap_or_else(|| key.clone());
+            let mut unique = base.clone();
+            let mut counter = 2;
+            while !used.insert(unique.clone()) {
+                unique = format!("{base}_{counter}");
+                counter += 1;
+            }
+            names[index] = if unique == base { name } else { Some(unique) };
+        }
+        names
+    }
+
+    fn get_members(&self, fields: &[Field]) -> Vec<Member> {
+        fields
+            .iter()
+            .map(|field| self.get_field_member(field))
+            .collect()
+    }
+
+    /// Classes for the groups and blocks of `fields`, nested ones first so each is declared
+    /// before the class using it
+    fn collect_nested_declarations(&self, fields: &[Field], declarations: &mut Vec<String>) {
+        for field in fields {
+            match field {
+                Field::Group(config) if !config.fields.is_empty() => {
+                    self.collect_nested_declarations(&config.fields, declarations);
+                    declarations.push(self.generate_class(
+                        &self.get_nested_type_name(config, &[]),
+                        &[],
+                        &self.get_members(&config.fields),
+                    ));
+                }
+                Field::Blocks(config) => {
+                    for (block_uid, block_fields) in config.block_types.iter().flatten() {
+                        self.collect_nested_declarations(block_fields, declarations);
+                        let block_name = self.get_nested_type_name(config, &[block_uid]);
+                        let mut members = self.get_members(block_fields);
+                        members.push(self.get_builtin_member(
+                            "_metadata",
+                            &self.options.get_builtin_name("BlockMetadata"),
+                            false,
+                        ));
+                        declarations.push(self.generate_class(&block_name, &[], &members));
+                        // Every item holds exactly one block, keyed by the block uid
+                        declarations.push(self.generate_class(
+                            &self.get_nested_type_name(config, &[block_uid, "block"]),
+                            &[],
+                            &[self.get_builtin_member(block_uid, &block_name, false)],
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Classes for a content type or global field, nested ones first
+    fn generate_model(&self, config: &GenerateConfig, is_content_type: bool) -> String {
+        let mut declarations = Vec::new();
+        self.collect_nested_declarations(&config.fields, &mut declarations);
+        let mut members = self.get_members(&config.fields);
+        // Resolved references carry the uid of their content type, which tells them apart
+        if is_content_type {
+            let uid = self.to_string_literal(&config.name);
+            let literal = format!("Literal[{uid}]");
+            members.push(if self.is_pydantic() {
+                Member {
+                    key: String::from("_content_type_uid"),
+                    annotation: literal,
+                    default: Some(uid),
+                    docs: String::new(),
+                }
+            } else {
+                self.get_builtin_member("_content_type_uid", &literal, true)
+            });
+        }
+        declarations.push(self.generate_class(
+            &self.options.get_type_name(&config.name),
+            &self.get_definition_paragraphs(config),
+            &members,
+        ));
+        // Two blank lines between top-level declarations
+        declarations.join("\n\n")
+    }
+
+    fn generate_imports(&self) -> String {
+        let typing = "from typing import Annotated, Any, Literal, Optional, Union";
+        if self.is_pydantic() {
+            format!("{typing}\n\nfrom pydantic import BaseModel, ConfigDict, Field\n")
+        } else {
+            format!("{typing}\n\nfrom typing_extensions import NotRequired, TypedDict\n")
+        }
+    }
+}
+
+impl Generator for PythonGenerator {
+    fn generate_text(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, &self.get_text_type(config), config.multiple))
+    }
+
+    fn generate_number(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, &self.get_number_type(config), config.multiple))
+    }
+
+    fn generate_date(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, "str", config.multiple))
+    }
+
+    fn generate_bool(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, "bool", config.multiple))
+    }
+
+    fn generate_file(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(
+            config,
+            &self.options.get_builtin_name("ContentstackFile"),
+            config.multiple,
+        ))
+    }
+
+    fn generate_link(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(
+            config,
+            &self.options.get_builtin_name("ContentstackLink"),
+            config.multiple,
+        ))
+    }
+
+    fn generate_json(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, &self.get_json_type(), config.multiple))
+    }
+
+    fn generate_reference(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(
+            config,
+            &self.get_reference_type(config),
+            config.multiple,
+        ))
+    }
+
+    fn generate_global_field(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(
+            config,
+            &self.get_global_field_type(config),
+            config.multiple,
+        ))
+    }
+
+    fn generate_blocks(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, &self.get_blocks_type(config), true))
+    }
+
+    fn generate_group(&self, config: &FieldConfig) -> String {
+        self.render_member(&self.get_member(config, &self.get_group_type(config), config.multiple))
+    }
+
+    /// Model of a content type; global fields are only told apart in `generate_document`, which
+    /// leaves out `_content_type_uid`
+    fn generate_interface(&self, config: &GenerateConfig) -> String {
+        self.generate_model(config, true)
+    }
+
+    fn get_builtins(&self) -> String {
+        let name = |name: &str| self.options.get_builtin_name(name);
+        let member = |key: &str, annotation: &str| self.get_builtin_member(key, annotation, false);
+        let optional = |key: &str, annotation: &str| self.get_builtin_member(key, annotation, true);
+
+        let mut builtins = Vec::new();
+        if self.is_pydantic() {
+            // Lets models be built from attribute names as well as aliases
+            builtins.push(format!(
+                "class {}(BaseModel):\n    model_config = ConfigDict(populate_by_name=True)\n",
+                name("ContentstackModel")
+            ));
+        }
+        builtins.extend([
+            self.generate_class(
+                &name("PublishDetails"),
+                &[],
+                &[
+                    member("environment", "str"),
+                    member("locale", "str"),
+                    member("time", "str"),
+                    member("user", "str"),
+                ],
+            ),
+            self.generate_class(
+                &name("ContentstackDimension"),
+                &[],
+                &[member("height", "int"), member("width", "int")],
+            ),
+            self.generate_class(
+                &name("ContentstackFile"),
+                &[],
+                &[
+                    member("uid", "str"),
+                    member("created_at", "str"),
+                    member("updated_at", "str"),
+                    member("created_by", "str"),
+                    member("updated_by", "str"),
+                    member("content_type", "str"),
+                    member("file_size", "str"),
+                    member("tags", "list[str]"),
+                    member("filename", "str"),
+                    member("url", "str"),
+                    member("ACL", "Any"),
+                    member("is_dir", "bool"),
+                    member("parent_uid", "Optional[str]"),
+                    member("_version", "int"),
+                    member("title", "str"),
+                    optional("_metadata", "dict[str, Any]"),
+                    optional("description", "str"),
+                    optional("dimension", &name("ContentstackDimension")),
+                    member("publish_details", &name("PublishDetails")),
+                ],
+            ),
+            self.generate_class(
+                &name("ContentstackLink"),
+                &[],
+                &[member("title", "str"), member("href", "str")],
+            ),
+            format!("{} = dict[str, Any]\n", self.get_json_type()),
+            self.generate_class(&name("BlockMetadata"), &[], &[member("uid", "str")]),
+            self.generate_class(
+                &name("ContentstackReference"),
+                &[String::from(
+                    "Referenced entry that the request did not include",
+                )],
+                &[member("uid", "str"), member("_content_type_uid", "str")],
+            ),
+        ]);
+        builtins.join("\n\n")
+    }
+
+    fn get_file_extension(&self) -> String {
+        String::from("py")
+    }
+
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut output = self.generate_imports();
+        output.push_str("\n\n");
+        output.push_str(&self.get_builtins());
+        for definition in global_fields {
+            output.push_str("\n\n");
+            output.push_str(&self.generate_model(definition, false));
+        }
+        for definition in content_types {
+            output.push_str("\n\n");
+            output.push_str(&self.generate_model(definition, true));
+        }
+
+        // Resolve the quoted references now that every model is declared
+        if self.is_pydantic() {
+            output.push_str("\n\n");
+            for definition in global_fields.iter().chain(content_types) {
+                output.push_str(&format!(
+                    "{}.model_rebuild()\n",
+                    self.options.get_type_name(&definition.name)
+                ));
+            }
+        }
+        output
+    }
+}
@@ -0,0 +1,597 @@
+use crate::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, get_definition_doc_paragraphs,
+    get_field_doc_paragraphs, get_line_comment, to_pascal_case, walk_fields,
+};
+use std::collections::HashSet;
+
+/// Keywords that cannot be used as field names without `r#`
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that cannot be raw identifiers either
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+const STRUCT_DERIVES: &str = "#[derive(Debug, Clone, Deserialize, Serialize)]";
+const SELECT_DERIVES: &str =
+    "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]";
+
+/// Rust structs with serde derives, for deserializing Delivery API responses
+pub struct RustGenerator {
+    options: GeneratorOptions,
+}
+
+impl RustGenerator {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self::with_options(GeneratorOptions::new(prefix, postfix))
+    }
+
+    pub fn with_options(options: GeneratorOptions) -> Self {
+        Self { options }
+    }
+
+    /// Indents every non-empty line of `code` by four spaces, as rustfmt does
+    fn indent(&self, code: &str) -> String {
+        let mut output = String::new();
+        for line in code.lines() {
+            if !line.is_empty() {
+                output.push_str("    ");
+                output.push_str(line);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// `///` comment from paragraphs, empty when there is nothing to say
+    fn get_doc_comment(&self, paragraphs: &[String]) -> String {
+        get_line_comment("///", paragraphs)
+    }
+
+    fn get_field_doc(&self, config: &FieldConfig) -> String {
+        self.get_doc_comment(&get_field_doc_paragraphs(config))
+    }
+
+    fn get_definition_doc(&self, config: &GenerateConfig) -> String {
+        let mut paragraphs = get_definition_doc_paragraphs(config);
+        paragraphs.extend(config.editor_url.as_ref().map(|url| format!("<{url}>")));
+        self.get_doc_comment(&paragraphs)
+    }
+
+    /// Snake-case field name for a uid, and whether serde has to rename it
+    fn get_field_name(&self, uid: &str) -> (String, bool) {
+        // `_metadata`, `_version` and friends read better without the underscore
+        let name: String = uid
+            .trim_start_matches('_')
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let name = match name.chars().next() {
+            Some(first) if first.is_ascii_alphabetic() => name,
+            Some(_) => format!("_{name}"),
+            None => String::from("field"),
+        };
+
+        if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+            (format!("{name}_"), true)
+        } else if KEYWORDS.contains(&name.as_str()) {
+            (format!("r#{name}"), name != uid)
+        } else {
+            let renamed = name != uid;
+            (name, renamed)
+        }
+    }
+
+    /// Field name for a uid that no other field in `used` has, and whether serde has to rename it
+    fn get_unique_field_name(&self, uid: &str, used: &mut HashSet<String>) -> (String, bool) {
+        let (name, renamed) = self.get_field_name(uid);
+        if used.insert(name.clone()) {
+            return (name, renamed);
+        }
+        // Suffixed names are never keywords, and always differ from the uid they stand for
+        let base = name.trim_start_matches("r#");
+        let mut counter = 2;
+        loop {
+            let unique = format!("{base}_{counter}");
+            if used.insert(unique.clone()) {
+                return (unique, true);
+            }
+            counter += 1;
+        }
+    }
+
+    /// PascalCase variant name for a select choice or block uid, unique within `used`
+    fn get_variant_name(&self, value: &str, used: &mut HashSet<String>) -> String {
+        let name: String = to_pascal_case(value)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let name = match name.chars().next() {
+            Some(first) if first.is_ascii_alphabetic() => name,
+            Some(_) => format!("_{name}"),
+            None => String::from("Empty"),
+        };
+
+        let mut unique = name.clone();
+        let mut counter = 2;
+        while !used.insert(unique.clone()) {
+            unique = format!("{name}{counter}");
+            counter += 1;
+        }
+        unique
+    }
+
+    /// Uid path of a field joined with `_`, the base of the names of its nested types
+    fn get_nested_name(&self, config: &FieldConfig) -> String {
+        if config.path.is_empty() {
+            config.name.clone()
+        } else {
+            config.path.join("_")
+        }
+    }
+
+    /// Name of the type declared for a select, group, modular blocks or multi-type reference field
+    fn get_nested_type_name(&self, config: &FieldConfig) -> String {
+        self.options.get_type_name(&self.get_nested_name(config))
+    }
+
+    fn get_block_type_name(&self, config: &FieldConfig, block_uid: &str) -> String {
+        self.options
+            .get_type_name(&format!("{}_{block_uid}", self.get_nested_name(config)))
+    }
+
+    fn get_json_type(&self) -> String {
+        self.options.get_builtin_name("ContentstackJson")
+    }
+
+    fn get_multiple_type(&self, multiple: bool, field_type: &str) -> String {
+        if multiple {
+            format!("Vec<{field_type}>")
+        } else {
+            field_type.to_string()
+        }
+    }
+
+    /// `pub name: Type,` member, preceded by the field's docs and a serde rename when needed
+    fn generate_member(
+        &self,
+        config: &FieldConfig,
+        field_type: &str,
+        used: &mut HashSet<String>,
+    ) -> String {
+        let (name, renamed) = self.get_unique_field_name(&config.name, used);
+        let field_type = if self.options.is_optional(config) {
+            format!("Option<{field_type}>")
+        } else {
+            field_type.to_string()
+        };
+
+        let mut member = self.get_field_doc(config);
+        if renamed {
+            member.push_str(&format!("#[serde(rename = {:?})]\n", config.name));
+        }
+        member.push_str(&format!("pub {name}: {field_type},"));
+        member
+    }
+
+    fn generate_property(&self, config: &FieldConfig, field_type: &str) -> String {
+        self.generate_member(
+            config,
+            &self.get_multiple_type(config.multiple, field_type),
+            &mut HashSet::new(),
+        )
+    }
+
+    /// Struct declaration from rendered members
+    fn generate_struct(&self, docs: &str, name: &str, members: &[String]) -> String {
+        if members.is_empty() {
+            return format!("{docs}{STRUCT_DERIVES}\npub struct {name} {{}}\n");
+        }
+        format!(
+            "{docs}{STRUCT_DERIVES}\npub struct {name} {{\n{}}}\n",
+            self.indent(&members.join("\n"))
+        )
+    }
+
+    /// Enum declaration from its attribute lines and rendered variants
+    fn generate_enum(&self, attributes: &str, name: &str, variants: &[String]) -> String {
+        format!(
+            "{attributes}\npub enum {name} {{\n{}}}\n",
+            self.indent(&variants.join("\n"))
+        )
+    }
+
+    /// Members of a struct, renamed where two uids map onto the same field name
+    fn generate_members(&self, fields: &[Field], used: &mut HashSet<String>) -> Vec<String> {
+        fields
+            .iter()
+            .map(|field| {
+                let (config, field_type) = self.get_member_type(field);
+                self.generate_member(config, &field_type, used)
+            })
+            .collect()
+    }
+
+    /// Config of a field and the type of its member, whichever kind it is
+    fn get_member_type<'a>(&self, field: &'a Field) -> (&'a FieldConfig, String) {
+        match field {
+            Field::Text(config) => (
+                config,
+                self.get_multiple_type(config.multiple, &self.get_text_type(config)),
+            ),
+            Field::Number(config) => (config, self.get_multiple_type(config.multiple, "f64")),
+            Field::Date(config) => (config, self.get_multiple_type(config.multiple, "String")),
+            Field::Bool(config) => (config, self.get_multiple_type(config.multiple, "bool")),
+            Field::File(config) => (
+                config,
+                self.get_multiple_type(
+                    config.multiple,
+                    &self.options.get_builtin_name("ContentstackFile"),
+                ),
+            ),
+            Field::Link(config) => (
+                config,
+                self.get_multiple_type(
+                    config.multiple,
+                    &self.options.get_builtin_name("ContentstackLink"),
+                ),
+            ),
+            Field::Json(config) => (
+                config,
+                self.get_multiple_type(config.multiple, &self.get_json_type()),
+            ),
+            Field::Reference(config) => (
+                config,
+                self.get_multiple_type(config.multiple, &self.get_reference_type(config)),
+            ),
+            Field::Global(config) => (
+                config,
+                self.get_multiple_type(config.multiple, &self.get_global_field_type(config)),
+            ),
+            Field::Blocks(config) => (
+                config,
+                self.get_multiple_type(true, &self.get_blocks_type(config)),
+            ),
+            Field::Group(config) => (
+                config,
+                self.get_multiple_type(config.multiple, &self.get_group_type(config)),
+            ),
+        }
+    }
+
+    fn get_text_type(&self, config: &FieldConfig) -> String {
+        match &config.enumeration {
+            Some(choices) if !choices.is_empty() => self.get_nested_type_name(config),
+            _ => String::from("String"),
+        }
+    }
+
+    fn get_reference_type(&self, config: &FieldConfig) -> String {
+        match config.reference_to.as_slice() {
+            [] => self.get_json_type(),
+            [uid] => format!(
+                "{}<{}>",
+                self.options.get_builtin_name("Reference"),
+                self.options.get_type_name(uid)
+            ),
+            _ => self.get_nested_type_name(config),
+        }
+    }
+
+    fn get_global_field_type(&self, config: &FieldConfig) -> String {
+        match config.reference_to.as_slice() {
+            [uid] => self.options.get_type_name(uid),
+            _ => self.get_json_type(),
+        }
+    }
+
+    fn get_blocks_type(&self, config: &FieldConfig) -> String {
+        match &config.block_types {
+            Some(block_types) if !block_types.is_empty() => self.get_nested_type_name(config),
+            _ => self.get_json_type(),
+        }
+    }
+
+    fn get_group_type(&self, config: &FieldConfig) -> String {
+        if config.fields.is_empty() {
+            self.get_json_type()
+        } else {
+            self.get_nested_type_name(config)
+        }
+    }
+
+    /// Enum of a text select's choices
+    fn generate_select_declaration(&self, config: &FieldConfig, choices: &[String]) -> String {
+        let mut used = HashSet::new();
+        let variants: Vec<String> = choices
+            .iter()
+            .map(|choice| {
+                format!(
+                    "#[serde(rename = {choice:?})]\n{},",
+                    self.get_variant_name(choice, &mut used)
+                )
+            })
+            .collect();
+        self.generate_enum(
+            SELECT_DERIVES,
+            &self.get_nested_type_name(config),
+            &variants,
+        )
+    }
+
+    fn generate_group_declaration(&self, config: &FieldConfig) -> String {
+        self.generate_struct(
+            "",
+            &self.get_nested_type_name(config),
+            &self.generate_members(&config.fields, &mut HashSet::new()),
+        )
+    }
+
+    /// Struct per block plus an externally tagged enum, matching `[{ "block_uid": { ... } }]`
+    fn generate_blocks_declaration(
+        &self,
+        config: &FieldConfig,
+        block_types: &[(String, Vec<Field>)],
+    ) -> String {
+        let mut used = HashSet::new();
+        let mut declarations = Vec::new();
+        let mut variants = Vec::new();
+        for (block_uid, fields) in block_types {
+            let block_name = self.get_block_type_name(config, block_uid);
+            // The block metadata keeps its name, a block field of the same name gets a suffix
+            let mut field_names = HashSet::from([String::from("metadata")]);
+            let mut members = self.generate_members(fields, &mut field_names);
+            members.push(format!(
+                "#[serde(rename = \"_metadata\")]\npub metadata: {},",
+                self.options.get_builtin_name("BlockMetadata")
+            ));
+            declarations.push(self.generate_struct("", &block_name, &members));
+            variants.push(format!(
+                "#[serde(rename = {block_uid:?})]\n{}({block_name}),",
+                self.get_variant_name(block_uid, &mut used)
+            ));
+        }
+        declarations.push(self.generate_enum(
+            STRUCT_DERIVES,
+            &self.get_nested_type_name(config),
+            &variants,
+        ));
+        declarations.join("\n")
+    }
+
+    /// Enum of the referenced content types, told apart by `_content_type_uid`, or the stub
+    fn generate_reference_declaration(&self, config: &FieldConfig) -> String {
+        let name = self.get_nested_type_name(config);
+        let entry_name = format!("{name}Entry");
+        let stub = self.options.get_builtin_name("ReferenceStub");
+        // The stub rejects unknown fields, so only an unresolved reference matches it
+        let reference = self.generate_enum(
+            &format!("{STRUCT_DERIVES}\n#[serde(untagged)]"),
+            &name,
+            &[format!("Stub({stub}),"), format!("Entry({entry_name}),")],
+        );
+
+        let mut used = HashSet::new();
+        let variants: Vec<String> = config
+            .reference_to
+            .iter()
+            .map(|uid| {
+                format!(
+                    "#[serde(rename = {uid:?})]\n{}(Box<{}>),",
+                    self.get_variant_name(uid, &mut used),
+                    self.options.get_type_name(uid)
+                )
+            })
+            .collect();
+        let entry = self.generate_enum(
+            &format!("{STRUCT_DERIVES}\n#[serde(tag = \"_content_type_uid\")]"),
+            &entry_name,
+            &variants,
+        );
+        format!("{reference}\n{entry}")
+    }
+
+    /// Types declared for the nested fields of a definition, in field order
+    fn generate_nested_declarations(&self, fields: &[Field]) -> Vec<String> {
+        let mut declarations = Vec::new();
+        walk_fields(fields, &mut |field| match field {
+            Field::Text(config) => {
+                if let Some(choices) = config.enumeration.as_ref().filter(|c| !c.is_empty()) {
+                    declarations.push(self.generate_select_declaration(config, choices));
+                }
+            }
+            Field::Reference(config) if config.reference_to.len() > 1 => {
+                declarations.push(self.generate_reference_declaration(config));
+            }
+            Field::Group(config) if !config.fields.is_empty() => {
+                declarations.push(self.generate_group_declaration(config));
+            }
+            Field::Blocks(config) => {
+                if let Some(block_types) = config.block_types.as_ref().filter(|b| !b.is_empty()) {
+                    declarations.push(self.generate_blocks_declaration(config, block_types));
+                }
+            }
+            _ => {}
+        });
+        declarations
+    }
+}
+
+impl Generator for RustGenerator {
+    fn generate_text(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_text_type(config))
+    }
+
+    fn generate_number(&self, config: &FieldConfig) -> String {
+        // serde cannot map numbers onto unit variants, so number selects stay numbers
+        self.generate_property(config, "f64")
+    }
+
+    fn generate_date(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, "String")
+    }
+
+    fn generate_bool(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, "bool")
+    }
+
+    fn generate_file(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.options.get_builtin_name("ContentstackFile"))
+    }
+
+    fn generate_link(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.options.get_builtin_name("ContentstackLink"))
+    }
+
+    fn generate_json(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_json_type())
+    }
+
+    fn generate_reference(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_reference_type(config))
+    }
+
+    fn generate_global_field(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_global_field_type(config))
+    }
+
+    fn generate_blocks(&self, config: &FieldConfig) -> String {
+        self.generate_member(
+            config,
+            &self.get_multiple_type(true, &self.get_blocks_type(config)),
+            &mut HashSet::new(),
+        )
+    }
+
+    fn generate_group(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_group_type(config))
+    }
+
+    fn generate_interface(&self, config: &GenerateConfig) -> String {
+        // Groups, blocks and selects are declared right before the struct using them
+        let mut declarations = self.generate_nested_declarations(&config.fields);
+        declarations.push(self.generate_struct(
+            &self.get_definition_doc(config),
+            &self.options.get_type_name(&config.name),
+            &self.generate_members(&config.fields, &mut HashSet::new()),
+        ));
+        declarations.join("\n")
+    }
+
+    fn get_builtins(&self) -> String {
+        let name = |name: &str| self.options.get_builtin_name(name);
+        let member = |uid: &str, field_type: &str| {
+            let (field_name, renamed) = self.get_field_name(uid);
+            let rename = if renamed {
+                format!("#[serde(rename = {uid:?})]\n")
+            } else {
+                String::new()
+            };
+            format!("{rename}pub {field_name}: {field_type},")
+        };
+        let reference = name("Reference");
+        let stub = name("ReferenceStub");
+
+        let builtins = [
+            self.generate_struct(
+                "",
+                &name("ContentstackFile"),
+                &[
+                    member("uid", "String"),
+                    member("created_at", "String"),
+                    member("updated_at", "String"),
+                    member("created_by", "String"),
+                    member("updated_by", "String"),
+                    member("content_type", "String"),
+                    member("file_size", "String"),
+                    member("tags", "Vec<String>"),
+                    member("filename", "String"),
+                    member("url", "String"),
+                    member("ACL", "serde_json::Value"),
+                    member("is_dir", "bool"),
+                    member("parent_uid", "Option<String>"),
+                    member("_version", "u32"),
+                    member("title", "String"),
+                    member("_metadata", "Option<serde_json::Value>"),
+                    member("description", "Option<String>"),
+                    member(
+                        "dimension",
+                        &format!("Option<{}>", name("ContentstackDimension")),
+                    ),
+                    member("publish_details", &name("PublishDetails")),
+                ],
+            ),
+            self.generate_struct(
+                "",
+                &name("ContentstackDimension"),
+                &[member("height", "u32"), member("width", "u32")],
+            ),
+            self.generate_struct(
+                "",
+                &name("PublishDetails"),
+                &[
+                    member("environment", "String"),
+                    member("locale", "String"),
+                    member("time", "String"),
+                    member("user", "String"),
+                ],
+            ),
+            self.generate_struct(
+                "",
+                &name("ContentstackLink"),
+                &[member("title", "String"), member("href", "String")],
+            ),
+            format!("pub type {} = serde_json::Value;\n", self.get_json_type()),
+            self.generate_struct("", &name("BlockMetadata"), &[member("uid", "String")]),
+            format!(
+                "{}{}",
+                self.get_doc_comment(&[String::from(
+                    "Referenced entry, resolved when the request includes the reference and a stub otherwise"
+                )]),
+                self.generate_enum(
+                    &format!("{STRUCT_DERIVES}\n#[serde(untagged)]"),
+                    &format!("{reference}<T>"),
+                    &[format!("Stub({stub}),"), String::from("Entry(Box<T>),")],
+                )
+            ),
+            format!(
+                "{}{STRUCT_DERIVES}\n#[serde(deny_unknown_fields)]\npub struct {stub} {{\n{}}}\n",
+                self.get_doc_comment(&[String::from(
+                    "Unresolved reference; anything more is a resolved entry"
+                )]),
+                self.indent(
+                    &[member("uid", "String"), member("_content_type_uid", "String")].join("\n")
+                )
+            ),
+        ];
+        builtins.join("\n")
+    }
+
+    fn get_file_extension(&self) -> String {
+        String::from("rs")
+    }
+
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut output = String::from("use serde::{Deserialize, Serialize};\n\n");
+        output.push_str(&self.get_builtins());
+        for definition in global_fields.iter().chain(content_types) {
+            output.push('\n');
+            output.push_str(&self.generate_interface(definition));
+        }
+        output
+    }
+}
@@ -1,66 +1,1211 @@
-use crate::generator::{Field, FieldConfig, GenerateConfig, Generator};
+use crate::error::{self, Error};
+use crate::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, Nullability, get_doc_heading,
+    to_pascal_case, walk_fields,
+};
+use clap::ValueEnum;
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+
+/// Words that are reserved in JavaScript/TypeScript (including strict mode).
+/// They are legal property names, but we quote them to keep the output unambiguous.
+const RESERVED_WORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Name of the type parameter that controls reference resolution in generic mode
+const RESOLVED_PARAMETER: &str = "Resolved";
+
+/// Lowercase markers in a description that flag a field or content type as deprecated
+const DEPRECATED_MARKERS: &[&str] = &["[deprecated]", "@deprecated"];
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum ReferenceStyle {
+    /// References are `Target | { _content_type_uid; uid }`
+    #[default]
+    Union,
+    /// Interfaces take a `Resolved` parameter selecting the full type or the stub
+    Generic,
+}
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum DeclarationStyle {
+    /// `export interface X { ... }`
+    #[default]
+    Interface,
+    /// `export type X = { ... };`
+    Type,
+}
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum SelectStyle {
+    /// Choices are an inline union of literals
+    #[default]
+    Inline,
+    /// A named `XChoices` tuple `as const` and a `X` type derived from it
+    Const,
+    /// A named TypeScript `enum`
+    Enum,
+}
+
+/// TypeScript type used for date fields
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DateType {
+    /// The ISO 8601 string returned by the API
+    #[default]
+    String,
+    /// A revived `Date`
+    Date,
+    /// `IsoDateString`, a branded string declared alongside the builtins
+    Branded,
+    /// Any other TypeScript type
+    Custom(String),
+}
+
+impl FromStr for DateType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Err(String::from("date type must not be empty")),
+            "string" => Ok(DateType::String),
+            "date" | "Date" => Ok(DateType::Date),
+            "branded" => Ok(DateType::Branded),
+            custom => Ok(DateType::Custom(custom.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum BuiltinsMode {
+    /// Declare every builtin type
+    #[default]
+    All,
+    /// Declare only the builtin types the schema uses
+    Used,
+    /// Do not declare builtin types
+    None,
+}
+
+/// Whether output is a regular module or ambient `.d.ts` declarations
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Ambient {
+    /// A regular `.ts` module
+    #[default]
+    None,
+    /// `.d.ts` declarations
+    Declarations,
+    /// `.d.ts` declarations wrapped in `declare module '<name>'`
+    Module(String),
+    /// `.d.ts` declarations wrapped in `declare namespace <name>`
+    Namespace(String),
+}
+
+/// Width of one level of indentation
+#[derive(Clone, Debug, PartialEq)]
+pub enum Indent {
+    Spaces(usize),
+    Tabs,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(2)
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum QuoteStyle {
+    /// `'value'`
+    #[default]
+    Single,
+    /// `"value"`
+    Double,
+}
+
+/// Code style of the generated TypeScript
+#[derive(Clone, Debug)]
+pub struct Formatting {
+    pub indent: Indent,
+    /// Quotes around string literals and module specifiers
+    pub quotes: QuoteStyle,
+    /// End statements and multi-line members with `;`
+    pub semicolons: bool,
+    /// Put a comma after the last item of multi-line lists such as enum members
+    pub trailing_commas: bool,
+}
+
+impl Default for Formatting {
+    fn default() -> Self {
+        Self {
+            indent: Indent::default(),
+            quotes: QuoteStyle::default(),
+            semicolons: true,
+            trailing_commas: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TypescriptOptions {
+    /// Naming and optionality shared with the other backends
+    pub common: GeneratorOptions,
+    /// How reference fields are typed
+    pub references: ReferenceStyle,
+    /// Whether object types are declared as interfaces or type aliases
+    pub declaration: DeclarationStyle,
+    /// How the choices of select fields are declared
+    pub selects: SelectStyle,
+    /// Type of date fields
+    pub date_type: DateType,
+    /// Type of number fields without choices, `number` when unset
+    pub number_type: Option<String>,
+    /// Type of file fields, `ContentstackFile` when unset
+    pub file_type: Option<String>,
+    /// Emit `readonly` properties and `ReadonlyArray<T>`
+    pub readonly: bool,
+    /// Emit ambient declarations instead of a regular module
+    pub ambient: Ambient,
+    /// Which builtin types are declared
+    pub builtins: BuiltinsMode,
+    /// Module to import builtin types from instead of declaring them
+    pub builtins_import: Option<String>,
+    /// Indentation, quotes and punctuation of the output
+    pub formatting: Formatting,
+    /// Add the `$` map of `data-cslp` attributes set by Live Preview's `addEditableTags`
+    pub editable_tags: bool,
+    /// Emit `XFieldPath` and `XReferencePath` unions of dot-separated field paths
+    pub field_paths: bool,
+    /// Emit `isX` type guards per content type and an `assertContentType` helper, unless ambient
+    pub type_guards: bool,
+}
 
 pub struct TypescriptGenerator {
-    prefix: String,
-    postfix: String,
+    options: TypescriptOptions,
 }
 
 impl TypescriptGenerator {
-    fn get_type_name(&self, name: &str) -> String {
-        self.to_pascal_case(name)
+    /// Name of the type generated for a content type or global field uid
+    pub(crate) fn get_type_name(&self, name: &str) -> String {
+        self.options.common.get_type_name(name)
+    }
+
+    /// Name of a builtin type such as `ContentstackFile`
+    pub(crate) fn get_builtin_name(&self, name: &str) -> String {
+        self.options.common.get_builtin_name(name)
+    }
+
+    fn get_date_type(&self) -> String {
+        match &self.options.date_type {
+            DateType::String => String::from("string"),
+            DateType::Date => String::from("Date"),
+            DateType::Branded => self.get_builtin_name("IsoDateString"),
+            DateType::Custom(custom) => custom.clone(),
+        }
+    }
+
+    fn get_number_type(&self) -> String {
+        self.options
+            .number_type
+            .clone()
+            .unwrap_or_else(|| String::from("number"))
+    }
+
+    fn get_file_type(&self) -> String {
+        self.options
+            .file_type
+            .clone()
+            .unwrap_or_else(|| self.get_builtin_name("ContentstackFile"))
+    }
+
+    fn is_generic(&self) -> bool {
+        matches!(self.options.references, ReferenceStyle::Generic)
+    }
+
+    fn get_reference_stub(&self, ref_type: &str) -> String {
+        let readonly = self.get_readonly_marker();
+        self.get_inline_object(&[
+            format!(
+                "{readonly}_content_type_uid: {}",
+                self.to_string_literal(ref_type)
+            ),
+            format!("{readonly}uid: string"),
+        ])
+    }
+
+    /// The `$` member Live Preview adds to entries, groups and blocks, empty when disabled
+    fn generate_editable_tags(&self, fields: &[Field]) -> String {
+        if !self.options.editable_tags || fields.is_empty() {
+            return String::new();
+        }
+
+        let keys = fields
+            .iter()
+            .map(|field| self.to_string_literal(&field.config().name))
+            .collect::<Vec<String>>()
+            .join(" | ");
+        let attribute =
+            self.get_inline_object(&[format!("{}: string", self.to_string_literal("data-cslp"))]);
+        format!(
+            "{}$?: {{ [K in {keys}]?: {attribute} }}{}\n",
+            self.get_readonly_marker(),
+            self.semi()
+        )
+    }
+
+    /// Single-line object type, members separated by `;` without a trailing one
+    pub(crate) fn get_inline_object(&self, members: &[String]) -> String {
+        format!("{{ {} }}", members.join("; "))
+    }
+
+    /// Terminator of statements and multi-line members
+    pub(crate) fn semi(&self) -> &'static str {
+        if self.options.formatting.semicolons {
+            ";"
+        } else {
+            ""
+        }
+    }
+
+    /// Indents every non-empty line of `code` by one level
+    pub(crate) fn indent(&self, code: &str) -> String {
+        let unit = match self.options.formatting.indent {
+            Indent::Spaces(width) => " ".repeat(width),
+            Indent::Tabs => String::from("\t"),
+        };
+        let mut output = String::new();
+        for line in code.lines() {
+            if !line.is_empty() {
+                output.push_str(&unit);
+                output.push_str(line);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    fn get_readonly_marker(&self) -> &'static str {
+        if self.options.readonly {
+            "readonly "
+        } else {
+            ""
+        }
     }
 
     fn get_mulitple_marker(&self, multiple: bool, content_type: &str) -> String {
-        if multiple {
+        if !multiple {
+            String::from(content_type)
+        } else if self.options.readonly {
+            format!("ReadonlyArray<{content_type}>")
+        } else {
             format!("Array<{content_type}>")
+        }
+    }
+
+    /// Literals of a select field's choices, `None` for anything that is not a select
+    fn get_select_literals(&self, field: &Field) -> Option<Vec<String>> {
+        match field {
+            Field::Text(config) => config.enumeration.as_ref().map(|choices| {
+                choices
+                    .iter()
+                    .map(|choice| self.to_string_literal(choice))
+                    .collect()
+            }),
+            Field::Number(config) => config.enumeration.as_ref().map(|choices| {
+                choices
+                    .iter()
+                    .map(|choice| self.to_number_literal(choice))
+                    .collect()
+            }),
+            _ => None,
+        }
+    }
+
+    fn get_select_name(&self, config: &FieldConfig) -> String {
+        if config.path.is_empty() {
+            config.name.clone()
         } else {
-            String::from(content_type)
+            config.path.join("_")
         }
     }
 
-    fn to_pascal_case(&self, s: &str) -> String {
-        // Convert any case to PascalCase
-        s.split(&['-', '_', ' '])
-            .filter(|part| !part.is_empty())
-            .map(|part| {
-                let mut chars = part.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => {
-                        first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
-                    }
-                }
-            })
-            .collect::<Vec<String>>()
-            .join("")
+    /// Type of a select field: the inline union or the name of the hoisted declaration
+    fn get_select_type(&self, config: &FieldConfig, literals: &[String]) -> String {
+        match self.options.selects {
+            SelectStyle::Inline => literals.join(" | "),
+            SelectStyle::Const | SelectStyle::Enum => {
+                self.get_type_name(&self.get_select_name(config))
+            }
+        }
     }
 
-    fn get_jsdoc(&self, s: Option<&str>) -> String {
-        match s {
-            Some(docs) => {
-                if docs.trim() != "" {
-                    format!("/** {docs} */\n")
+    fn get_enum_member_name(&self, value: &str, used: &mut HashSet<String>) -> String {
+        let name: String = to_pascal_case(value)
+            .chars()
+            .filter(|c| is_identifier_part(*c))
+            .collect();
+        let name = match name.chars().next() {
+            Some(first) if is_identifier_start(first) => name,
+            _ => format!("_{name}"),
+        };
+
+        let mut unique = name.clone();
+        let mut counter = 2;
+        while !used.insert(unique.clone()) {
+            unique = format!("{name}{counter}");
+            counter += 1;
+        }
+        unique
+    }
+
+    /// Hoisted declaration for a select field, empty for inline selects
+    fn generate_select_declaration(&self, config: &FieldConfig, literals: &[String]) -> String {
+        let name = self.get_select_name(config);
+        let type_name = self.get_type_name(&name);
+        match self.options.selects {
+            SelectStyle::Inline => String::new(),
+            SelectStyle::Const => {
+                let choices_name = self.get_type_name(&format!("{name}_choices"));
+                // Ambient declarations cannot have initializers, only the tuple type
+                let semi = self.semi();
+                let choices = if self.is_ambient() {
+                    format!(
+                        "export const {choices_name}: readonly [{}]{semi}",
+                        literals.join(", ")
+                    )
                 } else {
-                    String::new()
+                    format!(
+                        "export const {choices_name} = [{}] as const{semi}",
+                        literals.join(", ")
+                    )
+                };
+                format!(
+                    "{choices}\nexport type {type_name} = typeof {choices_name}[number]{semi}\n"
+                )
+            }
+            SelectStyle::Enum => {
+                let mut used = HashSet::new();
+                let choices = config.enumeration.iter().flatten();
+                let mut members = choices
+                    .zip(literals)
+                    .map(|(choice, literal)| {
+                        let member = self.get_enum_member_name(choice, &mut used);
+                        format!("{member} = {literal}")
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",\n");
+                if self.options.formatting.trailing_commas && !members.is_empty() {
+                    members.push(',');
+                }
+                format!("export enum {type_name} {{\n{}}}\n", self.indent(&members))
+            }
+        }
+    }
+
+    fn is_valid_identifier(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(is_identifier_start)
+            && chars.all(is_identifier_part)
+            && !RESERVED_WORDS.contains(&name)
+    }
+
+    pub(crate) fn to_string_literal(&self, value: &str) -> String {
+        let quote = match self.options.formatting.quotes {
+            QuoteStyle::Single => '\'',
+            QuoteStyle::Double => '"',
+        };
+        let mut literal = String::with_capacity(value.len() + 2);
+        literal.push(quote);
+        for c in value.chars() {
+            match c {
+                '\\' => literal.push_str("\\\\"),
+                c if c == quote => {
+                    literal.push('\\');
+                    literal.push(c);
                 }
+                '\n' => literal.push_str("\\n"),
+                '\r' => literal.push_str("\\r"),
+                '\t' => literal.push_str("\\t"),
+                // Line/paragraph separators and control characters are not allowed
+                // (or not readable) inside a string literal, so emit them as escapes
+                '\u{2028}' | '\u{2029}' => literal.push_str(&format!("\\u{:04x}", c as u32)),
+                c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+                c => literal.push(c),
+            }
+        }
+        literal.push(quote);
+        literal
+    }
+
+    pub(crate) fn to_number_literal(&self, value: &str) -> String {
+        match value.parse::<f64>() {
+            Ok(number) if number.is_finite() => value.to_string(),
+            _ => self.to_string_literal(value),
+        }
+    }
+
+    pub(crate) fn get_property_name(&self, name: &str) -> String {
+        if self.is_valid_identifier(name) {
+            name.to_string()
+        } else {
+            self.to_string_literal(name)
+        }
+    }
+
+    /// JSDoc comment from paragraphs and trailing tags, on one line when there is only one line
+    fn get_jsdoc(&self, paragraphs: &[String], tags: &[String]) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        for paragraph in paragraphs.iter().filter(|p| !p.trim().is_empty()) {
+            if !lines.is_empty() {
+                lines.push(String::new());
             }
+            lines.extend(
+                paragraph
+                    .trim()
+                    .lines()
+                    .map(|line| line.trim_end().to_string()),
+            );
+        }
+        if !lines.is_empty() && !tags.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(tags.iter().cloned());
+
+        // `*/` would end the comment early
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|line| line.replace("*/", "*\\/"))
+            .collect();
+        match lines.as_slice() {
+            [] => String::new(),
+            [line] => format!("/** {line} */\n"),
+            _ => {
+                let mut docs = String::from("/**\n");
+                for line in &lines {
+                    if line.is_empty() {
+                        docs.push_str(" *\n");
+                    } else {
+                        docs.push_str(&format!(" * {line}\n"));
+                    }
+                }
+                docs.push_str(" */\n");
+                docs
+            }
+        }
+    }
+
+    /// Description without deprecation markers, and whether it had one
+    fn split_deprecated(&self, description: Option<&str>) -> (String, bool) {
+        let mut description = description.unwrap_or_default().to_string();
+        let mut deprecated = false;
+        for marker in DEPRECATED_MARKERS {
+            while let Some(index) = description.to_ascii_lowercase().find(marker) {
+                description.replace_range(index..index + marker.len(), "");
+                deprecated = true;
+            }
+        }
+        (description.trim().to_string(), deprecated)
+    }
+
+    pub(crate) fn get_field_jsdoc(&self, config: &FieldConfig) -> String {
+        let (description, deprecated) = self.split_deprecated(config.description.as_deref());
+        let instruction = config.instruction.clone().unwrap_or_default();
+        let display_name = config.display_name.as_deref().unwrap_or_default();
+        // A bare uid path adds nothing worth a comment
+        if display_name.trim().is_empty()
+            && description.is_empty()
+            && instruction.trim().is_empty()
+            && !deprecated
+        {
+            return String::new();
+        }
+
+        let paragraphs = [
+            get_doc_heading(Some(display_name), &config.path.join(".")),
+            description,
+            instruction,
+        ];
+        let tags = if deprecated {
+            vec![String::from("@deprecated")]
+        } else {
+            vec![]
+        };
+        self.get_jsdoc(&paragraphs, &tags)
+    }
+
+    pub(crate) fn get_definition_jsdoc(&self, config: &GenerateConfig) -> String {
+        let (description, deprecated) = self.split_deprecated(config.description.as_deref());
+        let heading = match &config.title {
+            Some(_) => get_doc_heading(config.title.as_deref(), &config.name),
             None => String::new(),
+        };
+        let mut tags = Vec::new();
+        if deprecated {
+            tags.push(String::from("@deprecated"));
         }
+        if let Some(url) = &config.editor_url {
+            tags.push(format!("@see {url}"));
+        }
+        self.get_jsdoc(&[heading, description], &tags)
     }
 
     pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
-        Self {
-            prefix: prefix.unwrap_or("").to_string(),
-            postfix: postfix.unwrap_or("").to_string(),
+        Self::with_options(TypescriptOptions {
+            common: GeneratorOptions::new(prefix, postfix),
+            ..Default::default()
+        })
+    }
+
+    pub fn with_options(options: TypescriptOptions) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn options(&self) -> &TypescriptOptions {
+        &self.options
+    }
+
+    /// Opening line of an exported object type, e.g. `export interface Name {`
+    fn open_declaration(&self, name: &str) -> String {
+        match self.options.declaration {
+            DeclarationStyle::Interface => format!("export interface {name} {{\n"),
+            DeclarationStyle::Type => format!("export type {name} = {{\n"),
+        }
+    }
+
+    fn close_declaration(&self) -> String {
+        match self.options.declaration {
+            DeclarationStyle::Interface => String::from("}"),
+            DeclarationStyle::Type => format!("}}{}", self.semi()),
+        }
+    }
+
+    /// Builtin types referenced directly by the given fields
+    fn get_used_builtins(&self, fields: &[Field]) -> BTreeSet<String> {
+        let mut builtins = BTreeSet::new();
+        walk_fields(fields, &mut |field| match field {
+            Field::File(_) if self.options.file_type.is_none() => {
+                builtins.insert(self.get_builtin_name("ContentstackFile"));
+            }
+            Field::Link(_) => {
+                builtins.insert(self.get_builtin_name("ContentstackLink"));
+            }
+            Field::Json(_) => {
+                builtins.insert(self.get_builtin_name("ContentstackJson"));
+            }
+            Field::Date(_) if self.options.date_type == DateType::Branded => {
+                builtins.insert(self.get_builtin_name("IsoDateString"));
+            }
+            _ => {}
+        });
+        builtins
+    }
+
+    /// Builtin types needed to declare the given definitions, including the ones they depend on
+    fn get_required_builtins(&self, definitions: &[&GenerateConfig]) -> BTreeSet<String> {
+        let mut builtins = BTreeSet::new();
+        for definition in definitions {
+            builtins.extend(self.get_used_builtins(&definition.fields));
+        }
+        if builtins.contains(&self.get_builtin_name("ContentstackFile")) {
+            builtins.insert(self.get_builtin_name("PublishDetails"));
+            if self.options.date_type == DateType::Branded {
+                builtins.insert(self.get_builtin_name("IsoDateString"));
+            }
+        }
+        builtins
+    }
+
+    /// Builtin declarations for the given definitions, `None` when builtins are not declared here
+    pub fn generate_builtins(&self, definitions: &[&GenerateConfig]) -> Option<String> {
+        if self.options.builtins_import.is_some() {
+            return None;
+        }
+
+        match self.options.builtins {
+            BuiltinsMode::All => Some(self.get_builtins()),
+            BuiltinsMode::Used => {
+                let required = self.get_required_builtins(definitions);
+                let declarations = self
+                    .get_builtin_declarations()
+                    .into_iter()
+                    .filter(|(name, _)| required.contains(name))
+                    .map(|(_, declaration)| declaration)
+                    .collect::<Vec<String>>();
+                if declarations.is_empty() {
+                    None
+                } else {
+                    Some(declarations.join("\n"))
+                }
+            }
+            BuiltinsMode::None => None,
+        }
+    }
+
+    /// Import of the builtin types used by the given definitions when they live in another module
+    pub fn generate_builtins_import(&self, definitions: &[&GenerateConfig]) -> String {
+        let Some(module) = &self.options.builtins_import else {
+            return String::new();
+        };
+
+        let mut used = BTreeSet::new();
+        for definition in definitions {
+            used.extend(self.get_used_builtins(&definition.fields));
+        }
+        if used.is_empty() {
+            String::new()
+        } else {
+            self.generate_import(&used.into_iter().collect::<Vec<String>>(), module)
+        }
+    }
+
+    /// Module specifier other modules import builtin types from, `None` when they are not declared
+    fn get_builtins_specifier(&self) -> Option<String> {
+        match (&self.options.builtins_import, &self.options.builtins) {
+            (Some(module), _) => Some(module.clone()),
+            (None, BuiltinsMode::None) => None,
+            (None, _) => Some(String::from("./builtins")),
+        }
+    }
+
+    /// Uids of the content types and global fields the given definition refers to
+    fn get_referenced_definitions<'a>(&self, definition: &'a GenerateConfig) -> BTreeSet<&'a str> {
+        let mut uids = BTreeSet::new();
+        walk_fields(&definition.fields, &mut |field| {
+            if let Field::Reference(config) | Field::Global(config) = field {
+                uids.extend(config.reference_to.iter().map(String::as_str));
+            }
+        });
+        uids.remove(definition.name.as_str());
+        uids
+    }
+
+    /// Uids of the global fields used by the given fields
+    fn get_global_field_uids<'a>(&self, fields: &'a [Field]) -> BTreeSet<&'a str> {
+        let mut uids = BTreeSet::new();
+        walk_fields(fields, &mut |field| {
+            if let Field::Global(config) = field {
+                uids.extend(config.reference_to.iter().map(String::as_str));
+            }
+        });
+        uids
+    }
+
+    /// Collects the dot-separated paths of every field and of every reference field below `prefix`
+    ///
+    /// Global fields contribute template literals over the global field's own path unions.
+    fn collect_field_paths(
+        &self,
+        fields: &[Field],
+        prefix: &str,
+        paths: &mut Vec<String>,
+        reference_paths: &mut Vec<String>,
+    ) {
+        for field in fields {
+            let config = field.config();
+            let path = format!("{prefix}{}", config.name);
+            paths.push(self.to_string_literal(&path));
+
+            match field {
+                Field::Reference(_) => reference_paths.push(self.to_string_literal(&path)),
+                Field::Global(config) => {
+                    let template = self.to_template_prefix(&format!("{path}."));
+                    for uid in &config.reference_to {
+                        paths.push(format!(
+                            "`{template}${{{}}}`",
+                            self.get_type_name(&format!("{uid}_field_path"))
+                        ));
+                        reference_paths.push(format!(
+                            "`{template}${{{}}}`",
+                            self.get_type_name(&format!("{uid}_reference_path"))
+                        ));
+                    }
+                }
+                Field::Group(config) => {
+                    self.collect_field_paths(
+                        &config.fields,
+                        &format!("{path}."),
+                        paths,
+                        reference_paths,
+                    );
+                }
+                Field::Blocks(config) => {
+                    for (block_uid, block_fields) in config.block_types.iter().flatten() {
+                        let block_path = format!("{path}.{block_uid}");
+                        paths.push(self.to_string_literal(&block_path));
+                        self.collect_field_paths(
+                            block_fields,
+                            &format!("{block_path}."),
+                            paths,
+                            reference_paths,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Escapes text for the literal part of a template literal type
+    fn to_template_prefix(&self, value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('`', "\\`")
+            .replace("${", "\\${")
+    }
+
+    /// `XFieldPath` and `XReferencePath` unions for `only`/`except` and `includeReference`
+    fn generate_field_paths(&self, config: &GenerateConfig) -> String {
+        if !self.options.field_paths {
+            return String::new();
+        }
+
+        let mut paths = Vec::new();
+        let mut reference_paths = Vec::new();
+        self.collect_field_paths(&config.fields, "", &mut paths, &mut reference_paths);
+
+        let union = |items: Vec<String>| {
+            if items.is_empty() {
+                String::from("never")
+            } else {
+                items.join(" | ")
+            }
+        };
+        let semi = self.semi();
+        format!(
+            "\n\nexport type {} = {}{semi}\nexport type {} = {}{semi}",
+            self.get_type_name(&format!("{}_field_path", config.name)),
+            union(paths),
+            self.get_type_name(&format!("{}_reference_path", config.name)),
+            union(reference_paths),
+        )
+    }
+
+    /// File name of the module holding a definition (or `builtins`/`index`) in multi-file mode
+    pub fn get_module_file_name(&self, name: &str) -> String {
+        format!("{name}.{}", self.get_file_extension())
+    }
+
+    fn is_ambient(&self) -> bool {
+        self.options.ambient != Ambient::None
+    }
+
+    /// Wraps generated output in `declare module` or `declare namespace` when configured
+    pub fn wrap_ambient(&self, content: &str) -> String {
+        let declaration = match &self.options.ambient {
+            Ambient::None | Ambient::Declarations => return content.to_string(),
+            Ambient::Module(name) => format!("declare module {}", self.to_string_literal(name)),
+            Ambient::Namespace(name) => format!("declare namespace {name}"),
+        };
+
+        format!("{declaration} {{\n{}}}\n", self.indent(content))
+    }
+
+    fn generate_import(&self, names: &[String], specifier: &str) -> String {
+        format!(
+            "import type {{ {} }} from {}{}\n",
+            names.join(", "),
+            self.to_string_literal(specifier),
+            self.semi()
+        )
+    }
+
+    /// A standalone module for one definition, importing the types it refers to
+    pub fn generate_module(&self, definition: &GenerateConfig) -> String {
+        let mut imports = String::new();
+
+        let builtins: Vec<String> = self
+            .get_used_builtins(&definition.fields)
+            .into_iter()
+            .collect();
+        if let Some(specifier) = self.get_builtins_specifier()
+            && !builtins.is_empty()
+        {
+            imports.push_str(&self.generate_import(&builtins, &specifier));
+        }
+        let global_fields = self.get_global_field_uids(&definition.fields);
+        for uid in self.get_referenced_definitions(definition) {
+            let mut names = vec![self.get_type_name(uid)];
+            // Path unions of global fields are spliced into this definition's own unions
+            if self.options.field_paths && global_fields.contains(uid) {
+                names.push(self.get_type_name(&format!("{uid}_field_path")));
+                names.push(self.get_type_name(&format!("{uid}_reference_path")));
+            }
+            imports.push_str(&self.generate_import(&names, &format!("./{uid}")));
+        }
+        if !imports.is_empty() {
+            imports.push('\n');
+        }
+
+        format!("{imports}{}\n", self.generate_interface(definition))
+    }
+
+    /// The `index` barrel re-exporting every module, followed by the registry types
+    pub fn generate_index(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let definitions: Vec<&GenerateConfig> = global_fields.iter().chain(content_types).collect();
+
+        let mut index = String::new();
+        let mut modules = Vec::new();
+        if self.generate_builtins(&definitions).is_some() {
+            modules.push("builtins");
+        }
+        modules.extend(
+            definitions
+                .iter()
+                .map(|definition| definition.name.as_str()),
+        );
+        for module in modules {
+            index.push_str(&format!(
+                "export * from {}{}\n",
+                self.to_string_literal(&format!("./{module}")),
+                self.semi()
+            ));
+        }
+
+        index.push('\n');
+        for content_type in content_types {
+            index.push_str(&self.generate_import(
+                &[self.get_type_name(&content_type.name)],
+                &format!("./{}", content_type.name),
+            ));
+        }
+        if !content_types.is_empty() {
+            index.push('\n');
+        }
+
+        index.push_str(&self.generate_registry(content_types));
+        index.push('\n');
+        index
+    }
+
+    /// Top-level type names the output declares, each with what declares it
+    fn get_declared_names(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> Vec<(String, String)> {
+        let mut names = Vec::new();
+        for (kind, definitions) in [
+            ("global field", global_fields),
+            ("content type", content_types),
+        ] {
+            for definition in definitions {
+                names.push((
+                    self.get_type_name(&definition.name),
+                    format!("{kind} `{}`", definition.name),
+                ));
+            }
+        }
+        // Hoisted selects are named after their uid path, which can spell another type's name
+        if !matches!(self.options.selects, SelectStyle::Inline) {
+            for definition in global_fields.iter().chain(content_types) {
+                walk_fields(&definition.fields, &mut |field| {
+                    if self.get_select_literals(field).is_some() {
+                        let config = field.config();
+                        let path = if config.path.is_empty() {
+                            config.name.clone()
+                        } else {
+                            config.path.join(".")
+                        };
+                        names.push((
+                            self.get_type_name(&self.get_select_name(config)),
+                            format!("select field `{path}`"),
+                        ));
+                    }
+                });
+            }
+        }
+        for name in self.get_registry_names() {
+            names.push((name, String::from("the content type registry")));
+        }
+        names
+    }
+
+    /// Names of the registry types: `ContentTypeUid`, `AnyEntry` and `ContentTypeMap`
+    fn get_registry_names(&self) -> [String; 3] {
+        ["content_type_uid", "any_entry", "content_type_map"].map(|name| self.get_type_name(name))
+    }
+
+    /// Aggregate types over all content types: `ContentTypeUid`, `AnyEntry` and `ContentTypeMap`
+    pub fn generate_registry(&self, content_types: &[GenerateConfig]) -> String {
+        let union = |items: Vec<String>| {
+            if items.is_empty() {
+                String::from("never")
+            } else {
+                items.join(" | ")
+            }
+        };
+
+        let uids = union(
+            content_types
+                .iter()
+                .map(|content_type| self.to_string_literal(&content_type.name))
+                .collect(),
+        );
+        let entries = union(
+            content_types
+                .iter()
+                .map(|content_type| self.get_type_name(&content_type.name))
+                .collect(),
+        );
+
+        let [uid_name, entry_name, map_name] = self.get_registry_names();
+        let semi = self.semi();
+        let mut output = format!(
+            "export type {uid_name} = {uids}{semi}\n\nexport type {entry_name} = {entries}{semi}\n\n"
+        );
+        output.push_str(&self.open_declaration(&map_name));
+        let mut members = String::new();
+        for content_type in content_types {
+            members.push_str(&format!(
+                "{}: {}{semi}\n",
+                self.get_property_name(&content_type.name),
+                self.get_type_name(&content_type.name)
+            ));
+        }
+        output.push_str(&self.indent(&members));
+        output.push_str(&self.close_declaration());
+
+        // Ambient declarations have no implementation to back the guards
+        if self.options.type_guards && !self.is_ambient() {
+            for content_type in content_types {
+                output.push_str("\n\n");
+                output.push_str(&self.generate_type_guard(content_type));
+            }
+            output.push_str("\n\n");
+            output.push_str(&self.generate_assert_content_type());
+        }
+        output
+    }
+
+    /// Exported function with the given signature and body
+    fn generate_function(&self, signature: &str, body: &str) -> String {
+        format!("export function {signature} {{\n{}}}", self.indent(body))
+    }
+
+    /// Parameter type of the type guards, anything carrying a content type uid
+    fn get_guard_parameter_type(&self) -> String {
+        self.get_inline_object(&[format!(
+            "{}_content_type_uid: string",
+            self.get_readonly_marker()
+        )])
+    }
+
+    /// `isX(entry): entry is X`, narrowing on `_content_type_uid`
+    fn generate_type_guard(&self, content_type: &GenerateConfig) -> String {
+        let type_name = self.get_type_name(&content_type.name);
+        self.generate_function(
+            &format!(
+                "is{type_name}(entry: {}): entry is {type_name}",
+                self.get_guard_parameter_type()
+            ),
+            &format!(
+                "return entry._content_type_uid === {}{}",
+                self.to_string_literal(&content_type.name),
+                self.semi()
+            ),
+        )
+    }
+
+    /// `assertContentType(entry, uid)`, throwing unless the entry has the given content type
+    fn generate_assert_content_type(&self) -> String {
+        let uid_type = self.get_type_name("content_type_uid");
+        let map_type = self.get_type_name("content_type_map");
+        let body = format!(
+            "if (entry._content_type_uid !== uid) {{\n{}}}",
+            self.indent(&format!(
+                "throw new Error(`Expected a ${{uid}} entry, got ${{entry._content_type_uid}}`){}",
+                self.semi()
+            ))
+        );
+        self.generate_function(
+            &format!(
+                "assertContentType<U extends {uid_type}>(entry: {}, uid: U): asserts entry is {map_type}[U]",
+                self.get_guard_parameter_type()
+            ),
+            &body,
+        )
+    }
+
+    /// Every builtin declaration, keyed by its (possibly affixed) name
+    fn get_builtin_declarations(&self) -> Vec<(String, String)> {
+        let file = self.get_builtin_name("ContentstackFile");
+        let publish_details = self.get_builtin_name("PublishDetails");
+        let link = self.get_builtin_name("ContentstackLink");
+        let json = self.get_builtin_name("ContentstackJson");
+
+        let string = || String::from("string");
+        let readonly = self.get_readonly_marker();
+        let date = || self.get_date_type();
+        let dimension = format!(
+            "{{\n{}}}",
+            self.generate_builtin_members(&[
+                ("height", false, String::from("number")),
+                ("width", false, String::from("number")),
+            ])
+        );
+
+        let file_declaration = self.generate_builtin(
+            &file,
+            &[
+                ("uid", false, string()),
+                ("created_at", false, date()),
+                ("updated_at", false, date()),
+                ("created_by", false, string()),
+                ("updated_by", false, string()),
+                ("content_type", false, string()),
+                ("file_size", false, string()),
+                ("tags", false, format!("{readonly}string[]")),
+                ("filename", false, string()),
+                ("url", false, string()),
+                ("ACL", false, format!("{readonly}any[] | object")),
+                ("is_dir", false, String::from("boolean")),
+                ("parent_uid", false, string()),
+                ("_version", false, String::from("number")),
+                ("title", false, string()),
+                ("_metadata", true, String::from("object")),
+                ("description", true, string()),
+                ("dimension", true, dimension),
+                ("publish_details", false, publish_details.clone()),
+            ],
+        );
+        let publish_details_declaration = self.generate_builtin(
+            &publish_details,
+            &[
+                ("environment", false, string()),
+                ("locale", false, string()),
+                ("time", false, date()),
+                ("user", false, string()),
+            ],
+        );
+        let link_declaration = self.generate_builtin(
+            &link,
+            &[("title", false, string()), ("href", false, string())],
+        );
+        let json_declaration =
+            self.generate_builtin(&json, &[("[key: string]", false, String::from("any"))]);
+
+        let mut builtins = vec![
+            (file, file_declaration),
+            (publish_details, publish_details_declaration),
+            (link, link_declaration),
+            (json, json_declaration),
+        ];
+
+        if self.options.date_type == DateType::Branded {
+            let iso_date_string = self.get_builtin_name("IsoDateString");
+            let declaration = format!(
+                "export type {iso_date_string} = string & {}{}\n",
+                self.get_inline_object(&[format!(
+                    "readonly __brand: {}",
+                    self.to_string_literal("IsoDateString")
+                )]),
+                self.semi()
+            );
+            builtins.push((iso_date_string, declaration));
+        }
+        builtins
+    }
+
+    /// Renders a builtin type from `(name, optional, type)` members
+    fn generate_builtin(&self, name: &str, members: &[(&str, bool, String)]) -> String {
+        let mut interface = self.open_declaration(name);
+        interface.push_str(&self.generate_builtin_members(members));
+        interface.push_str(&self.close_declaration());
+        interface.push('\n');
+        interface
+    }
+
+    fn generate_builtin_members(&self, members: &[(&str, bool, String)]) -> String {
+        let mut output = String::new();
+        for (member, optional, member_type) in members {
+            let readonly = self.get_readonly_marker();
+            let optional_marker = if *optional { "?" } else { "" };
+            output.push_str(&self.indent(&format!(
+                "{readonly}{member}{optional_marker}: {member_type}{}",
+                self.semi()
+            )));
+        }
+        output
+    }
+
+    pub(crate) fn is_optional(&self, config: &FieldConfig) -> bool {
+        self.options.common.is_optional(config)
+    }
+
+    fn is_nullable(&self, config: &FieldConfig) -> bool {
+        self.is_optional(config)
+            && !matches!(self.options.common.nullability, Nullability::Optional)
+    }
+
+    fn get_nullable_type(&self, config: &FieldConfig, field_type: &str) -> String {
+        if self.is_nullable(config) {
+            format!("{field_type} | null")
+        } else {
+            field_type.to_string()
         }
     }
 
     fn _generate_field_type(&self, config: &FieldConfig) -> String {
-        let docs = self.get_jsdoc(config.description.as_deref());
-        let optional_marker = if config.optional { "?" } else { "" };
-        let field_name = &config.name;
+        let docs = self.get_field_jsdoc(config);
+        let optional_marker = match self.options.common.nullability {
+            Nullability::Optional | Nullability::OptionalNullable if self.is_optional(config) => {
+                "?"
+            }
+            _ => "",
+        };
+        let readonly = self.get_readonly_marker();
+        let field_name = self.get_property_name(&config.name);
 
-        format!("{docs}{field_name}{optional_marker}: ")
+        format!("{docs}{readonly}{field_name}{optional_marker}: ")
     }
 }
 
@@ -71,14 +1216,18 @@ impl Generator for TypescriptGenerator {
             .enumeration
             .as_ref()
             .map_or(String::from("string"), |choices| {
-                choices
+                let literals = choices
                     .iter()
-                    .map(|choice| format!("'{choice}'"))
-                    .collect::<Vec<String>>()
-                    .join(" | ")
+                    .map(|choice| self.to_string_literal(choice))
+                    .collect::<Vec<String>>();
+                self.get_select_type(config, &literals)
             });
         let multiple_text_type = self.get_mulitple_marker(config.multiple, &text_type);
-        format!("{base}{multiple_text_type};")
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &multiple_text_type),
+            self.semi()
+        )
     }
 
     fn generate_number(&self, config: &FieldConfig) -> String {
@@ -86,60 +1235,121 @@ impl Generator for TypescriptGenerator {
         let number_type = config
             .enumeration
             .as_ref()
-            .map_or(String::from("number"), |choices| choices.join(" | "));
+            .map_or(self.get_number_type(), |choices| {
+                let literals = choices
+                    .iter()
+                    .map(|choice| self.to_number_literal(choice))
+                    .collect::<Vec<String>>();
+                self.get_select_type(config, &literals)
+            });
         let multiple_number_type = self.get_mulitple_marker(config.multiple, &number_type);
-        format!("{base}{multiple_number_type};",)
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &multiple_number_type),
+            self.semi()
+        )
     }
 
     fn generate_date(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
-        let date_type = self.get_mulitple_marker(config.multiple, "string");
-        format!("{base}{date_type};",)
+        let date_type = self.get_mulitple_marker(config.multiple, &self.get_date_type());
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &date_type),
+            self.semi()
+        )
     }
 
     fn generate_bool(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
         let boolean_type = self.get_mulitple_marker(config.multiple, "boolean");
-        format!("{base}{boolean_type};")
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &boolean_type),
+            self.semi()
+        )
     }
 
     fn generate_file(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
-        let file_type = self.get_mulitple_marker(config.multiple, "ContentstackFile");
-        format!("{base}{file_type};")
+        let file_type = self.get_mulitple_marker(config.multiple, &self.get_file_type());
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &file_type),
+            self.semi()
+        )
     }
 
     fn generate_link(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
-        let link_type = self.get_mulitple_marker(config.multiple, "ContentstackLink");
-        format!("{base}{link_type};")
+        let link_type =
+            self.get_mulitple_marker(config.multiple, &self.get_builtin_name("ContentstackLink"));
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &link_type),
+            self.semi()
+        )
     }
 
     fn generate_json(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
-        let json_type = self.get_mulitple_marker(config.multiple, "ContentstackJson");
-        format!("{base}{json_type};")
+        let json_type =
+            self.get_mulitple_marker(config.multiple, &self.get_builtin_name("ContentstackJson"));
+        format!(
+            "{base}{}{}",
+            self.get_nullable_type(config, &json_type),
+            self.semi()
+        )
     }
 
     fn generate_reference(&self, config: &FieldConfig) -> String {
         let base = self._generate_field_type(config);
         let any_type = self.get_mulitple_marker(config.multiple, "any");
         if config.reference_to.is_empty() {
-            format!("{base}{any_type};")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &any_type),
+                self.semi()
+            )
         } else {
-            let types = config
-                .reference_to
-                .iter()
-                .map(|ref_type| {
-                    format!(
-                        "{} | {{ _content_type_uid: '{ref_type}'; uid: string; }}",
-                        self.get_type_name(ref_type),
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(" | ");
-            let multiple_types = self.get_mulitple_marker(config.multiple, &types);
-            format!("{base}{multiple_types};")
+            let types = if self.is_generic() {
+                let resolved = config
+                    .reference_to
+                    .iter()
+                    .map(|ref_type| self.get_type_name(ref_type))
+                    .collect::<Vec<String>>()
+                    .join(" | ");
+                let stubs = config
+                    .reference_to
+                    .iter()
+                    .map(|ref_type| self.get_reference_stub(ref_type))
+                    .collect::<Vec<String>>()
+                    .join(" | ");
+                format!("{RESOLVED_PARAMETER} extends true ? {resolved} : {stubs}")
+            } else {
+                config
+                    .reference_to
+                    .iter()
+                    .map(|ref_type| {
+                        format!(
+                            "{} | {}",
+                            self.get_type_name(ref_type),
+                            self.get_reference_stub(ref_type),
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            };
+            let mut multiple_types = self.get_mulitple_marker(config.multiple, &types);
+            // A conditional type has to be wrapped before `| null` is appended
+            if self.is_generic() && !config.multiple && self.is_nullable(config) {
+                multiple_types = format!("({multiple_types})");
+            }
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &multiple_types),
+                self.semi()
+            )
         }
     }
 
@@ -147,16 +1357,31 @@ impl Generator for TypescriptGenerator {
         let base = self._generate_field_type(config);
         let any_type = self.get_mulitple_marker(config.multiple, "any");
         if config.reference_to.is_empty() {
-            format!("{base}{any_type};")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &any_type),
+                self.semi()
+            )
         } else {
             let types = config
                 .reference_to
                 .iter()
-                .map(|ref_type| self.get_type_name(ref_type))
+                .map(|ref_type| {
+                    // Global fields pass the resolution mode on to their own references
+                    if self.is_generic() {
+                        format!("{}<{RESOLVED_PARAMETER}>", self.get_type_name(ref_type))
+                    } else {
+                        self.get_type_name(ref_type)
+                    }
+                })
                 .collect::<Vec<String>>()
                 .join(" | ");
             let multiple_types = self.get_mulitple_marker(config.multiple, &types);
-            format!("{base}{multiple_types};")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &multiple_types),
+                self.semi()
+            )
         }
     }
 
@@ -164,39 +1389,50 @@ impl Generator for TypescriptGenerator {
         let base = self._generate_field_type(config);
         if let Some(block_types) = &config.block_types {
             if block_types.is_empty() {
-                format!("{base}Array<any>;")
+                format!(
+                    "{base}{}{}",
+                    self.get_nullable_type(config, &self.get_mulitple_marker(true, "any")),
+                    self.semi()
+                )
             } else {
-                // Generate object type with block uids as keys
-                let mut block_definitions = String::new();
+                // Every item holds exactly one block, keyed by the block uid
+                let readonly = self.get_readonly_marker();
+                let mut variants = Vec::new();
                 for (block_uid, fields) in block_types {
                     let mut block_fields = String::new();
                     for field in fields {
-                        let field_code = match field {
-                            Field::Text(config) => self.generate_text(config),
-                            Field::Number(config) => self.generate_number(config),
-                            Field::Date(config) => self.generate_date(config),
-                            Field::Bool(config) => self.generate_bool(config),
-                            Field::File(config) => self.generate_file(config),
-                            Field::Link(config) => self.generate_link(config),
-                            Field::Json(config) => self.generate_json(config),
-                            Field::Reference(config) => self.generate_reference(config),
-                            Field::Global(config) => self.generate_global_field(config),
-                            Field::Blocks(config) => self.generate_blocks(config),
-                            Field::Group(config) => self.generate_group(config),
-                        };
+                        let field_code = self.generate_field(field);
 
-                        // Add proper indentation for nested fields
-                        for line in field_code.lines() {
-                            block_fields.push_str(&format!("    {line}\n"));
-                        }
+                        block_fields.push_str(&field_code);
+                        block_fields.push('\n');
                     }
-                    block_definitions
-                        .push_str(&format!("  {block_uid}: {{\n{block_fields}  }};\n"));
+                    block_fields.push_str(&self.generate_editable_tags(fields));
+                    block_fields.push_str(&format!(
+                        "{readonly}_metadata: {}{}\n",
+                        self.get_inline_object(&[format!("{readonly}uid: string")]),
+                        self.semi()
+                    ));
+                    let block_key = self.get_property_name(block_uid);
+                    let block = format!(
+                        "{readonly}{block_key}: {{\n{}}}{}",
+                        self.indent(&block_fields),
+                        self.semi()
+                    );
+                    variants.push(format!("{{\n{}}}", self.indent(&block)));
                 }
-                format!("{base}Array<{{\n{block_definitions}}}>;")
+                let blocks_type = self.get_mulitple_marker(true, &variants.join(" | "));
+                format!(
+                    "{base}{}{}",
+                    self.get_nullable_type(config, &blocks_type),
+                    self.semi()
+                )
             }
         } else {
-            format!("{base}Array<any>;")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &self.get_mulitple_marker(true, "any")),
+                self.semi()
+            )
         }
     }
 
@@ -204,183 +1440,246 @@ impl Generator for TypescriptGenerator {
         let base = self._generate_field_type(config);
         let any_type = self.get_mulitple_marker(config.multiple, "any");
         if config.fields.is_empty() {
-            format!("{base}{any_type};")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &any_type),
+                self.semi()
+            )
         } else {
             // Generate inline object type instead of referencing a separate type
             let mut group_fields = String::new();
             for field in &config.fields {
-                let field_code = match field {
-                    Field::Text(config) => self.generate_text(config),
-                    Field::Number(config) => self.generate_number(config),
-                    Field::Date(config) => self.generate_date(config),
-                    Field::Bool(config) => self.generate_bool(config),
-                    Field::File(config) => self.generate_file(config),
-                    Field::Link(config) => self.generate_link(config),
-                    Field::Json(config) => self.generate_json(config),
-                    Field::Reference(config) => self.generate_reference(config),
-                    Field::Global(config) => self.generate_global_field(config),
-                    Field::Blocks(config) => self.generate_blocks(config),
-                    Field::Group(config) => self.generate_group(config),
-                };
+                let field_code = self.generate_field(field);
 
                 // Add proper indentation for nested fields
-                for line in field_code.lines() {
-                    group_fields.push_str(&format!("  {line}\n"));
-                }
+                group_fields.push_str(&self.indent(&field_code));
             }
+            group_fields.push_str(&self.indent(&self.generate_editable_tags(&config.fields)));
             let multiple_types =
                 self.get_mulitple_marker(config.multiple, &format!("{{\n{group_fields}}}"));
-            format!("{base}{multiple_types}")
+            format!(
+                "{base}{}{}",
+                self.get_nullable_type(config, &multiple_types),
+                self.semi()
+            )
         }
     }
 
     fn generate_interface(&self, config: &GenerateConfig) -> String {
-        let docs = self.get_jsdoc(config.description.as_deref());
-        let type_name = format!(
-            "{}{}{}",
-            self.prefix,
-            self.get_type_name(&config.name),
-            self.postfix
-        );
+        let docs = self.get_definition_jsdoc(config);
+        let type_name = self.get_type_name(&config.name);
 
-        let mut interface = format!("{docs}export interface {type_name} {{\n");
+        let type_parameters = if self.is_generic() {
+            format!("<{RESOLVED_PARAMETER} extends boolean = false>")
+        } else {
+            String::new()
+        };
+
+        // Selects declared outside of the interface go right before it
+        let mut declarations = String::new();
+        walk_fields(&config.fields, &mut |field| {
+            if let Some(literals) = self.get_select_literals(field) {
+                let declaration = self.generate_select_declaration(field.config(), &literals);
+                if !declaration.is_empty() {
+                    declarations.push_str(&declaration);
+                    declarations.push('\n');
+                }
+            }
+        });
+
+        let mut interface = format!(
+            "{declarations}{docs}{}",
+            self.open_declaration(&format!("{type_name}{type_parameters}"))
+        );
 
-        interface.push_str(&format!("  _content_type_uid: '{}'\n", &config.name));
+        interface.push_str(&self.indent(&format!(
+            "{}_content_type_uid: {}{}",
+            self.get_readonly_marker(),
+            self.to_string_literal(&config.name),
+            self.semi()
+        )));
         for field in &config.fields {
-            let field_code = match field {
-                Field::Text(config) => self.generate_text(config),
-                Field::Number(config) => self.generate_number(config),
-                Field::Date(config) => self.generate_date(config),
-                Field::Bool(config) => self.generate_bool(config),
-                Field::File(config) => self.generate_file(config),
-                Field::Link(config) => self.generate_link(config),
-                Field::Json(config) => self.generate_json(config),
-                Field::Reference(config) => self.generate_reference(config),
-                Field::Global(config) => self.generate_global_field(config),
-                Field::Blocks(config) => self.generate_blocks(config),
-                Field::Group(config) => self.generate_group(config),
-            };
+            let field_code = self.generate_field(field);
 
-            for line in field_code.lines() {
-                interface.push_str(&format!("  {line}\n"));
-            }
+            interface.push_str(&self.indent(&field_code));
+        }
+        interface.push_str(&self.indent(&self.generate_editable_tags(&config.fields)));
+
+        interface.push_str(&self.close_declaration());
+
+        if self.is_generic() {
+            let resolved_name = self.get_type_name(&format!("{}_resolved", config.name));
+            interface.push_str(&format!(
+                "\nexport type {resolved_name} = {type_name}<true>{}",
+                self.semi()
+            ));
         }
+        interface.push_str(&self.generate_field_paths(config));
 
-        interface.push('}');
         interface
     }
 
-    fn get_builtins(&self) -> String {
-        "export interface ContentstackFile {
-  uid: string;
-  created_at: string;
-  updated_at: string;
-  created_by: string;
-  updated_by: string;
-  content_type: string;
-  file_size: string;
-  tags: string[];
-  filename: string;
-  url: string;
-  ACL: any[] | object;
-  is_dir: boolean;
-  parent_uid: string;
-  _version: number;
-  title: string;
-  _metadata?: object;
-  description?: string;
-  dimension?: {
-    height: number;
-    width: number;
-  };
-  publish_details: PublishDetails;
-}
+    fn get_file_extension(&self) -> String {
+        if self.is_ambient() {
+            String::from("d.ts")
+        } else {
+            String::from("ts")
+        }
+    }
 
-export interface PublishDetails {
-  environment: string;
-  locale: string;
-  time: string;
-  user: string;
-}
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let definitions: Vec<&GenerateConfig> = global_fields.iter().chain(content_types).collect();
 
-export interface ContentstackLink {
-  title: string;
-  href: string;
-}
+        // Add builtin types (or their import) at the beginning
+        let mut output = self.generate_builtins_import(&definitions);
+        if let Some(builtins) = self.generate_builtins(&definitions) {
+            output.push_str(&builtins);
+        }
+        if !output.is_empty() {
+            output.push('\n');
+        }
 
-export interface ContentstackJson {
-  [key: string]: any;
-}
-"
-        .to_string()
-    }
-}
+        // Global field types first, then content types
+        for definitions in [global_fields, content_types] {
+            let interfaces: Vec<String> = definitions
+                .par_iter()
+                .map(|definition| self.generate_interface(definition))
+                .collect();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            for interface in interfaces {
+                output.push_str(&interface);
+                output.push('\n');
+            }
+        }
 
-    #[test]
-    fn test_to_pascal_case_snake_case() {
-        let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("snake_case"), "SnakeCase");
-        assert_eq!(generator.to_pascal_case("user_profile"), "UserProfile");
-        assert_eq!(generator.to_pascal_case("api_key_config"), "ApiKeyConfig");
-    }
+        output.push_str(&self.generate_registry(content_types));
+        output.push('\n');
 
-    #[test]
-    fn test_to_pascal_case_kebab_case() {
-        let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("kebab-case"), "KebabCase");
-        assert_eq!(generator.to_pascal_case("user-profile"), "UserProfile");
-        assert_eq!(generator.to_pascal_case("api-key-config"), "ApiKeyConfig");
+        self.wrap_ambient(&output)
     }
 
-    #[test]
-    fn test_to_pascal_case_space_separated() {
-        let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("space case"), "SpaceCase");
-        assert_eq!(generator.to_pascal_case("user profile"), "UserProfile");
-        assert_eq!(generator.to_pascal_case("api key config"), "ApiKeyConfig");
+    fn check_names(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> error::Result<()> {
+        let mut declared = HashMap::new();
+        for (name, source) in self.get_declared_names(global_fields, content_types) {
+            if let Some(first) = declared.insert(name.clone(), source.clone()) {
+                return Err(Error::Output(format!(
+                    "{name} would be declared for both {first} and {source}; rename one of them"
+                )));
+            }
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_to_pascal_case_mixed_separators() {
-        let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("mixed-case_test"), "MixedCaseTest");
-        assert_eq!(
-            generator.to_pascal_case("api-key_config test"),
-            "ApiKeyConfigTest"
+    /// One module per global field and content type, plus `builtins` and an `index` barrel
+    fn generate_modules(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> Option<Vec<(String, String)>> {
+        let definitions: Vec<&GenerateConfig> = global_fields.iter().chain(content_types).collect();
+
+        let mut modules = Vec::new();
+        if let Some(builtins) = self.generate_builtins(&definitions) {
+            modules.push((self.get_module_file_name("builtins"), builtins));
+        }
+
+        modules.par_extend(
+            global_fields
+                .par_iter()
+                .chain(content_types.par_iter())
+                .map(|definition| {
+                    (
+                        self.get_module_file_name(&definition.name),
+                        self.generate_module(definition),
+                    )
+                }),
         );
+
+        modules.push((
+            self.get_module_file_name("index"),
+            self.generate_index(global_fields, content_types),
+        ));
+
+        Some(modules)
     }
 
+    fn get_builtins(&self) -> String {
+        self.get_builtin_declarations()
+            .into_iter()
+            .map(|(_, declaration)| declaration)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// Whether `c` may start an ECMAScript identifier (`ID_Start`, `$` or `_`)
+fn is_identifier_start(c: char) -> bool {
+    c == '$' || c == '_' || unicode_ident::is_xid_start(c)
+}
+
+/// Whether `c` may continue an ECMAScript identifier (`ID_Continue`, `$`, ZWNJ or ZWJ)
+fn is_identifier_part(c: char) -> bool {
+    c == '$' || c == '\u{200c}' || c == '\u{200d}' || unicode_ident::is_xid_continue(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
-    fn test_to_pascal_case_single_word() {
+    fn test_is_valid_identifier() {
         let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("user"), "User");
-        assert_eq!(generator.to_pascal_case("test"), "Test");
-        assert_eq!(generator.to_pascal_case("API"), "Api");
+        assert!(generator.is_valid_identifier("title"));
+        assert!(generator.is_valid_identifier("_metadata"));
+        assert!(generator.is_valid_identifier("$price"));
+        assert!(generator.is_valid_identifier("field_2"));
+        assert!(generator.is_valid_identifier("überschrift"));
+        assert!(!generator.is_valid_identifier("x²"));
+        assert!(!generator.is_valid_identifier("half½"));
+        assert!(generator.is_valid_identifier("cafe\u{301}"));
+        assert!(!generator.is_valid_identifier(""));
+        assert!(!generator.is_valid_identifier("2nd_title"));
+        assert!(!generator.is_valid_identifier("hero-banner"));
+        assert!(!generator.is_valid_identifier("with space"));
+        assert!(!generator.is_valid_identifier("default"));
+        assert!(!generator.is_valid_identifier("class"));
     }
 
     #[test]
-    fn test_to_pascal_case_empty_string() {
+    fn test_get_property_name() {
         let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case(""), "");
+        assert_eq!(generator.get_property_name("title"), "title");
+        assert_eq!(generator.get_property_name("123"), "'123'");
+        assert_eq!(generator.get_property_name("hero-banner"), "'hero-banner'");
+        assert_eq!(generator.get_property_name("it's"), "'it\\'s'");
     }
 
     #[test]
-    fn test_to_pascal_case_multiple_separators() {
+    fn test_to_string_literal_escapes() {
         let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("test__case"), "TestCase");
-        assert_eq!(generator.to_pascal_case("test--case"), "TestCase");
-        assert_eq!(generator.to_pascal_case("test  case"), "TestCase");
+        assert_eq!(generator.to_string_literal("plain"), "'plain'");
+        assert_eq!(generator.to_string_literal("it's"), "'it\\'s'");
+        assert_eq!(generator.to_string_literal("a\\b"), "'a\\\\b'");
+        assert_eq!(generator.to_string_literal("a\nb\r\t"), "'a\\nb\\r\\t'");
+        assert_eq!(generator.to_string_literal("\u{2028}"), "'\\u2028'");
+        assert_eq!(generator.to_string_literal("\u{0}"), "'\\u0000'");
+        assert_eq!(generator.to_string_literal("\"quoted\""), "'\"quoted\"'");
     }
 
     #[test]
-    fn test_to_pascal_case_leading_trailing_separators() {
+    fn test_to_number_literal() {
         let generator = TypescriptGenerator::new(None, None);
-        assert_eq!(generator.to_pascal_case("_test_case_"), "TestCase");
-        assert_eq!(generator.to_pascal_case("-test-case-"), "TestCase");
-        assert_eq!(generator.to_pascal_case(" test case "), "TestCase");
+        assert_eq!(generator.to_number_literal("42"), "42");
+        assert_eq!(generator.to_number_literal("-1.5"), "-1.5");
+        assert_eq!(generator.to_number_literal("NaN"), "'NaN'");
+        assert_eq!(generator.to_number_literal("large"), "'large'");
     }
 }
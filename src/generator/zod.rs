@@ -0,0 +1,416 @@
+use crate::generator::typescript::{DateType, TypescriptGenerator, TypescriptOptions};
+use crate::generator::{Field, FieldConfig, GenerateConfig, Generator, Nullability};
+use crate::order::dependency_order;
+use std::collections::HashSet;
+
+/// Zod schemas mirroring the TypeScript output, for validating API responses at runtime
+pub struct ZodGenerator {
+    /// Naming, literals, docs and formatting are shared with the TypeScript output
+    typescript: TypescriptGenerator,
+    /// Schemas declared above the one being generated, which can be referenced directly
+    declared: HashSet<String>,
+}
+
+impl ZodGenerator {
+    pub fn new(prefix: Option<&str>, postfix: Option<&str>) -> Self {
+        Self {
+            typescript: TypescriptGenerator::new(prefix, postfix),
+            declared: HashSet::new(),
+        }
+    }
+
+    pub fn with_options(options: TypescriptOptions) -> Self {
+        Self {
+            typescript: TypescriptGenerator::with_options(options),
+            declared: HashSet::new(),
+        }
+    }
+
+    /// Generator for a schema declared after the given ones
+    fn after(&self, declared: HashSet<String>) -> Self {
+        Self {
+            typescript: TypescriptGenerator::with_options(self.options().clone()),
+            declared,
+        }
+    }
+
+    fn options(&self) -> &TypescriptOptions {
+        self.typescript.options()
+    }
+
+    /// Name of the schema generated for a content type or global field uid
+    fn get_schema_name(&self, name: &str) -> String {
+        format!("{}Schema", self.typescript.get_type_name(name))
+    }
+
+    /// Name of the schema of a builtin type such as `ContentstackFile`
+    fn get_builtin_schema_name(&self, name: &str) -> String {
+        format!("{}Schema", self.typescript.get_builtin_name(name))
+    }
+
+    fn get_date_schema(&self) -> String {
+        match self.options().date_type {
+            DateType::Date => String::from("z.coerce.date()"),
+            DateType::Branded => format!(
+                "z.string().brand<{}>()",
+                self.typescript
+                    .to_string_literal(&self.typescript.get_builtin_name("IsoDateString"))
+            ),
+            // A custom TypeScript type has no schema to check it; the cli rejects it for zod
+            DateType::String | DateType::Custom(_) => String::from("z.string()"),
+        }
+    }
+
+    fn get_multiple_schema(&self, multiple: bool, schema: &str) -> String {
+        if multiple {
+            format!("z.array({schema})")
+        } else {
+            schema.to_string()
+        }
+    }
+
+    /// Appends `.optional()`, `.nullable()` or `.nullish()` to optional fields as configured
+    fn get_optional_schema(&self, config: &FieldConfig, schema: &str) -> String {
+        if !self.typescript.is_optional(config) {
+            return schema.to_string();
+        }
+        match self.options().common.nullability {
+            Nullability::Optional => format!("{schema}.optional()"),
+            Nullability::Nullable => format!("{schema}.nullable()"),
+            Nullability::OptionalNullable => format!("{schema}.nullish()"),
+        }
+    }
+
+    /// `name: schema` property of an object schema, preceded by the field's docs
+    fn generate_property(&self, config: &FieldConfig, schema: &str) -> String {
+        let schema = self.get_multiple_schema(config.multiple, schema);
+        format!(
+            "{}{}: {}",
+            self.typescript.get_field_jsdoc(config),
+            self.typescript.get_property_name(&config.name),
+            self.get_optional_schema(config, &schema)
+        )
+    }
+
+    /// Object literal `{ ... }` from rendered properties
+    fn generate_object_literal(&self, properties: &[String]) -> String {
+        if properties.is_empty() {
+            return String::from("{}");
+        }
+
+        let mut body = properties.join(",\n");
+        if self.options().formatting.trailing_commas {
+            body.push(',');
+        }
+        format!("{{\n{}}}", self.typescript.indent(&body))
+    }
+
+    /// `z.object({ ... })` from rendered properties
+    fn generate_object(&self, properties: &[String]) -> String {
+        format!("z.object({})", self.generate_object_literal(properties))
+    }
+
+    fn generate_properties(&self, fields: &[Field]) -> Vec<String> {
+        fields
+            .iter()
+            .map(|field| self.generate_field(field))
+            .collect()
+    }
+
+    /// `z.union([...])`, or the only member as Zod needs at least two
+    fn generate_union(&self, mut schemas: Vec<String>) -> String {
+        match schemas.len() {
+            0 => String::from("z.never()"),
+            1 => schemas.remove(0),
+            _ => format!("z.union([{}])", schemas.join(", ")),
+        }
+    }
+
+    /// Schema of another definition, resolved lazily unless it is declared above
+    fn get_definition_schema(&self, uid: &str) -> String {
+        if self.declared.contains(uid) {
+            return self.get_schema_name(uid);
+        }
+        // Only cycles get here in a document; the explicit return type keeps TypeScript from
+        // inferring a circular type, at the cost of the inferred type of this property
+        format!("z.lazy((): z.ZodTypeAny => {})", self.get_schema_name(uid))
+    }
+
+    fn get_reference_stub(&self, uid: &str) -> String {
+        format!(
+            "z.object({{ _content_type_uid: z.literal({}), uid: z.string() }})",
+            self.typescript.to_string_literal(uid)
+        )
+    }
+
+    /// `export const XSchema = z.object({ ... })` for a content type or global field
+    fn generate_schema(&self, config: &GenerateConfig, is_content_type: bool) -> String {
+        let mut properties = Vec::new();
+        // Only entries fetched with their content type carry the uid, and global fields never do
+        if is_content_type {
+            properties.push(format!(
+                "_content_type_uid: z.literal({}).optional()",
+                self.typescript.to_string_literal(&config.name)
+            ));
+        }
+        properties.extend(self.generate_properties(&config.fields));
+        format!(
+            "{}export const {} = {}{}",
+            self.typescript.get_definition_jsdoc(config),
+            self.get_schema_name(&config.name),
+            self.generate_object(&properties),
+            self.typescript.semi()
+        )
+    }
+
+    fn generate_registry(&self, content_types: &[GenerateConfig]) -> String {
+        let members: Vec<String> = content_types
+            .iter()
+            .map(|content_type| {
+                format!(
+                    "{}: {}",
+                    self.typescript.get_property_name(&content_type.name),
+                    self.get_schema_name(&content_type.name)
+                )
+            })
+            .collect();
+        format!(
+            "export const {} = {} as const{}\n",
+            self.typescript.get_type_name("content_type_schemas"),
+            self.generate_object_literal(&members),
+            self.typescript.semi()
+        )
+    }
+}
+
+impl Generator for ZodGenerator {
+    fn generate_text(&self, config: &FieldConfig) -> String {
+        let schema = match &config.enumeration {
+            Some(choices) if choices.is_empty() => String::from("z.never()"),
+            Some(choices) => format!(
+                "z.enum([{}])",
+                choices
+                    .iter()
+                    .map(|choice| self.typescript.to_string_literal(choice))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            None => String::from("z.string()"),
+        };
+        self.generate_property(config, &schema)
+    }
+
+    fn generate_number(&self, config: &FieldConfig) -> String {
+        let schema = match &config.enumeration {
+            Some(choices) => self.generate_union(
+                choices
+                    .iter()
+                    .map(|choice| {
+                        format!("z.literal({})", self.typescript.to_number_literal(choice))
+                    })
+                    .collect(),
+            ),
+            None => String::from("z.number()"),
+        };
+        self.generate_property(config, &schema)
+    }
+
+    fn generate_date(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_date_schema())
+    }
+
+    fn generate_bool(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, "z.boolean()")
+    }
+
+    fn generate_file(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_builtin_schema_name("ContentstackFile"))
+    }
+
+    fn generate_link(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_builtin_schema_name("ContentstackLink"))
+    }
+
+    fn generate_json(&self, config: &FieldConfig) -> String {
+        self.generate_property(config, &self.get_builtin_schema_name("ContentstackJson"))
+    }
+
+    fn generate_reference(&self, config: &FieldConfig) -> String {
+        if config.reference_to.is_empty() {
+            return self.generate_property(config, "z.any()");
+        }
+
+        let schemas = config
+            .reference_to
+            .iter()
+            .flat_map(|uid| {
+                [
+                    self.get_definition_schema(uid),
+                    self.get_reference_stub(uid),
+                ]
+            })
+            .collect();
+        self.generate_property(config, &self.generate_union(schemas))
+    }
+
+    fn generate_global_field(&self, config: &FieldConfig) -> String {
+        if config.reference_to.is_empty() {
+            return self.generate_property(config, "z.any()");
+        }
+
+        let schemas = config
+            .reference_to
+            .iter()
+            .map(|uid| self.get_definition_schema(uid))
+            .collect();
+        self.generate_property(config, &self.generate_union(schemas))
+    }
+
+    fn generate_blocks(&self, config: &FieldConfig) -> String {
+        let block_types = config.block_types.iter().flatten();
+        let variants: Vec<String> = block_types
+            .map(|(block_uid, fields)| {
+                // Every item holds exactly one block, keyed by the block uid
+                let mut properties = self.generate_properties(fields);
+                properties.push(String::from("_metadata: z.object({ uid: z.string() })"));
+                self.generate_object(&[format!(
+                    "{}: {}",
+                    self.typescript.get_property_name(block_uid),
+                    self.generate_object(&properties)
+                )])
+            })
+            .collect();
+
+        let items = if variants.is_empty() {
+            String::from("z.any()")
+        } else {
+            self.generate_union(variants)
+        };
+        let schema = format!("z.array({items})");
+        format!(
+            "{}{}: {}",
+            self.typescript.get_field_jsdoc(config),
+            self.typescript.get_property_name(&config.name),
+            self.get_optional_schema(config, &schema)
+        )
+    }
+
+    fn generate_group(&self, config: &FieldConfig) -> String {
+        if config.fields.is_empty() {
+            return self.generate_property(config, "z.any()");
+        }
+        self.generate_property(
+            config,
+            &self.generate_object(&self.generate_properties(&config.fields)),
+        )
+    }
+
+    /// Schema of a content type; a `GenerateConfig` does not say which kind it is, so global
+    /// fields are only told apart in `generate_document`, which leaves out `_content_type_uid`
+    fn generate_interface(&self, config: &GenerateConfig) -> String {
+        self.generate_schema(config, true)
+    }
+
+    fn get_builtins(&self) -> String {
+        let date = self.get_date_schema();
+        let publish_details = self.get_builtin_schema_name("PublishDetails");
+        let dimension = self.generate_object(&[
+            String::from("height: z.number()"),
+            String::from("width: z.number()"),
+        ]);
+
+        // Declared before use, as these are evaluated eagerly
+        let builtins = [
+            (
+                publish_details.clone(),
+                self.generate_object(&[
+                    String::from("environment: z.string()"),
+                    String::from("locale: z.string()"),
+                    format!("time: {date}"),
+                    String::from("user: z.string()"),
+                ]),
+            ),
+            (
+                self.get_builtin_schema_name("ContentstackFile"),
+                self.generate_object(&[
+                    String::from("uid: z.string()"),
+                    format!("created_at: {date}"),
+                    format!("updated_at: {date}"),
+                    String::from("created_by: z.string()"),
+                    String::from("updated_by: z.string()"),
+                    String::from("content_type: z.string()"),
+                    String::from("file_size: z.string()"),
+                    String::from("tags: z.array(z.string())"),
+                    String::from("filename: z.string()"),
+                    String::from("url: z.string()"),
+                    String::from("ACL: z.any()"),
+                    String::from("is_dir: z.boolean()"),
+                    String::from("parent_uid: z.string().nullable()"),
+                    String::from("_version: z.number()"),
+                    String::from("title: z.string()"),
+                    String::from("_metadata: z.record(z.string(), z.any()).optional()"),
+                    String::from("description: z.string().optional()"),
+                    format!("dimension: {dimension}.optional()"),
+                    format!("publish_details: {publish_details}"),
+                ]),
+            ),
+            (
+                self.get_builtin_schema_name("ContentstackLink"),
+                self.generate_object(&[
+                    String::from("title: z.string()"),
+                    String::from("href: z.string()"),
+                ]),
+            ),
+            (
+                self.get_builtin_schema_name("ContentstackJson"),
+                String::from("z.record(z.string(), z.any())"),
+            ),
+        ];
+
+        builtins
+            .iter()
+            .map(|(name, schema)| {
+                format!("export const {name} = {schema}{}\n", self.typescript.semi())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn get_file_extension(&self) -> String {
+        String::from("zod.ts")
+    }
+
+    fn generate_document(
+        &self,
+        global_fields: &[GenerateConfig],
+        content_types: &[GenerateConfig],
+    ) -> String {
+        let mut output = format!(
+            "import {{ z }} from {}{}\n\n",
+            self.typescript.to_string_literal("zod"),
+            self.typescript.semi()
+        );
+        output.push_str(&self.get_builtins());
+        output.push('\n');
+        // Schemas are constants, so referenced ones are declared first and only cycles are lazy
+        let definitions: Vec<(&GenerateConfig, bool)> = global_fields
+            .iter()
+            .map(|definition| (definition, false))
+            .chain(content_types.iter().map(|definition| (definition, true)))
+            .collect();
+        let configs: Vec<&GenerateConfig> = definitions
+            .iter()
+            .map(|(definition, _)| *definition)
+            .collect();
+        let mut declared = HashSet::new();
+        for index in dependency_order(&configs) {
+            let (definition, is_content_type) = definitions[index];
+            let generator = self.after(declared.clone());
+            output.push_str(&generator.generate_schema(definition, is_content_type));
+            output.push_str("\n\n");
+            declared.insert(definition.name.clone());
+        }
+        output.push_str(&self.generate_registry(content_types));
+        output
+    }
+}
@@ -3,4 +3,6 @@ pub mod args;
 pub mod contentstack;
 pub mod error;
 pub mod generator;
+pub mod order;
 pub mod region;
+pub mod target;
@@ -1,13 +1,6 @@
-mod app;
-mod args;
-mod contentstack;
-mod error;
-mod generator;
-mod region;
-
-use app::App;
-use args::Args;
 use clap::Parser;
+use cs_ts::app::App;
+use cs_ts::args::Args;
 
 fn main() {
     let args = Args::parse();
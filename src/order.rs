@@ -0,0 +1,96 @@
+use crate::generator::{Field, GenerateConfig, walk_fields};
+use clap::ValueEnum;
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Clone, Debug, ValueEnum, Default)]
+pub enum Order {
+    /// Keep the order returned by the API
+    #[default]
+    Schema,
+    /// Sort by uid
+    Alphabetical,
+    /// Place every type after the types it references, ties sorted by uid
+    Topological,
+}
+
+impl Order {
+    pub fn sort(&self, definitions: &mut Vec<GenerateConfig>) {
+        match self {
+            Order::Schema => {}
+            Order::Alphabetical => definitions.sort_by(|a, b| a.name.cmp(&b.name)),
+            Order::Topological => sort_topologically(definitions),
+        }
+    }
+}
+
+/// Sorts fields by uid, recursing into groups and modular blocks
+pub fn sort_fields(fields: &mut [Field]) {
+    fields.sort_by(|a, b| a.config().name.cmp(&b.config().name));
+    for field in fields {
+        let config = field.config_mut();
+        sort_fields(&mut config.fields);
+        if let Some(block_types) = &mut config.block_types {
+            block_types.sort_by(|a, b| a.0.cmp(&b.0));
+            for (_, block_fields) in block_types {
+                sort_fields(block_fields);
+            }
+        }
+    }
+}
+
+/// Uids of the content types and global fields a definition refers to
+fn dependencies(definition: &GenerateConfig) -> BTreeSet<&str> {
+    let mut dependencies = BTreeSet::new();
+    walk_fields(&definition.fields, &mut |field| {
+        if let Field::Reference(config) | Field::Global(config) = field {
+            dependencies.extend(config.reference_to.iter().map(String::as_str));
+        }
+    });
+    dependencies
+}
+
+fn sort_topologically(definitions: &mut Vec<GenerateConfig>) {
+    definitions.sort_by(|a, b| a.name.cmp(&b.name));
+    let order = dependency_order(&definitions.iter().collect::<Vec<&GenerateConfig>>());
+
+    let mut slots: Vec<Option<GenerateConfig>> = definitions.drain(..).map(Some).collect();
+    definitions.extend(order.into_iter().filter_map(|index| slots[index].take()));
+}
+
+/// Indices of `definitions` with every definition after the ones it references, otherwise
+/// keeping the given order; a reference that closes a cycle is ignored
+pub fn dependency_order(definitions: &[&GenerateConfig]) -> Vec<usize> {
+    let index_by_uid: HashMap<&str, usize> = definitions
+        .iter()
+        .enumerate()
+        .map(|(index, definition)| (definition.name.as_str(), index))
+        .collect();
+    let edges: Vec<Vec<usize>> = definitions
+        .iter()
+        .map(|definition| {
+            dependencies(definition)
+                .into_iter()
+                .filter_map(|uid| index_by_uid.get(uid).copied())
+                .collect()
+        })
+        .collect();
+
+    // Depth-first post-order; references back into the current path (cycles) are skipped
+    let mut visited = vec![false; definitions.len()];
+    let mut order = Vec::with_capacity(definitions.len());
+    for root in 0..definitions.len() {
+        visit(root, &edges, &mut visited, &mut order);
+    }
+    order
+}
+
+fn visit(index: usize, edges: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+    if visited[index] {
+        return;
+    }
+    visited[index] = true;
+    for &dependency in &edges[index] {
+        visit(dependency, edges, visited, order);
+    }
+    order.push(index);
+}
@@ -7,3 +7,14 @@ pub enum Region {
     NorthAmerica,
     Australia,
 }
+
+impl Region {
+    /// Base URL of the Contentstack web app for this region
+    pub fn app_url(&self) -> &'static str {
+        match self {
+            Region::Europe => "https://eu-app.contentstack.com",
+            Region::NorthAmerica => "https://app.contentstack.com",
+            Region::Australia => "https://au-app.contentstack.com",
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use clap::ValueEnum;
+
+/// Output format of a generator
+#[derive(Clone, Debug, ValueEnum, Default, PartialEq)]
+pub enum Target {
+    /// TypeScript interfaces
+    #[default]
+    Typescript,
+    /// Zod schemas
+    Zod,
+    /// JSON Schema (draft 2020-12)
+    JsonSchema,
+    /// Rust structs with serde derives
+    Rust,
+    /// Go structs with JSON tags
+    Go,
+    /// Python Pydantic models or `TypedDict`s
+    Python,
+}
+
+impl Target {
+    /// What the target's output holds, for status messages
+    pub fn label(&self) -> &'static str {
+        match self {
+            Target::Typescript => "TypeScript types",
+            Target::Zod => "Zod schemas",
+            Target::JsonSchema => "JSON Schema",
+            Target::Rust => "Rust structs",
+            Target::Go => "Go structs",
+            Target::Python => "Python models",
+        }
+    }
+}
@@ -1,17 +1,14 @@
+mod common;
+
+use clap::Parser;
+use common::parse_args;
 use cs_ts::app::App;
 use cs_ts::args::Args;
-use cs_ts::region::Region;
+use cs_ts::error::Error;
 
 #[test]
 fn test_app_initialization_with_valid_credentials() {
-    let args = Args {
-        region: Region::NorthAmerica,
-        api_key: "test_key".to_string(),
-        access_token: "test_token".to_string(),
-        output: None,
-        prefix: None,
-        postfix: None,
-    };
+    let args = parse_args("test_key", "test_token", &[]);
 
     let app = App::new(args);
     assert!(app.is_ok());
@@ -19,14 +16,7 @@ fn test_app_initialization_with_valid_credentials() {
 
 #[test]
 fn test_app_initialization_with_empty_api_key() {
-    let args = Args {
-        region: Region::NorthAmerica,
-        api_key: "".to_string(),
-        access_token: "test_token".to_string(),
-        output: None,
-        prefix: None,
-        postfix: None,
-    };
+    let args = parse_args("", "test_token", &[]);
 
     let app = App::new(args);
     assert!(app.is_err());
@@ -34,14 +24,7 @@ fn test_app_initialization_with_empty_api_key() {
 
 #[test]
 fn test_app_initialization_with_empty_access_token() {
-    let args = Args {
-        region: Region::NorthAmerica,
-        api_key: "test_key".to_string(),
-        access_token: "".to_string(),
-        output: None,
-        prefix: None,
-        postfix: None,
-    };
+    let args = parse_args("test_key", "", &[]);
 
     let app = App::new(args);
     assert!(app.is_err());
@@ -49,15 +32,159 @@ fn test_app_initialization_with_empty_access_token() {
 
 #[test]
 fn test_app_initialization_with_whitespace_credentials() {
-    let args = Args {
-        region: Region::NorthAmerica,
-        api_key: "   ".to_string(),
-        access_token: "   ".to_string(),
-        output: None,
-        prefix: None,
-        postfix: None,
-    };
+    let args = parse_args("   ", "   ", &[]);
 
     let app = App::new(args);
     assert!(app.is_err());
 }
+
+#[test]
+fn test_ambient_declarations_reject_runtime_selects() {
+    for style in ["const", "enum"] {
+        let args = parse_args("test_key", "test_token", &["--dts", "--selects", style]);
+        assert!(matches!(App::new(args), Err(Error::Config(_))));
+    }
+    let args = parse_args(
+        "test_key",
+        "test_token",
+        &["--declare-namespace", "Cms", "--selects", "inline"],
+    );
+    assert!(App::new(args).is_ok());
+}
+
+#[test]
+fn test_ambient_declarations_require_dts_output() {
+    let args = parse_args("test_key", "test_token", &["--dts", "-o", "types.ts"]);
+    assert!(matches!(App::new(args), Err(Error::Config(_))));
+    let args = parse_args("test_key", "test_token", &["--dts", "-o", "types.d.ts"]);
+    assert!(App::new(args).is_ok());
+    let args = parse_args(
+        "test_key",
+        "test_token",
+        &[
+            "--dts",
+            "-o",
+            "types.ts",
+            "--target",
+            "typescript",
+            "--target",
+            "zod",
+        ],
+    );
+    assert!(App::new(args).is_ok());
+}
+
+#[test]
+fn test_builtins_from_conflicts_with_declare_namespace() {
+    let args = Args::try_parse_from([
+        "cs-ts",
+        "-k",
+        "test_key",
+        "-t",
+        "test_token",
+        "--builtins-from",
+        "@acme/cms-builtins",
+        "--declare-namespace",
+        "Cms",
+    ]);
+    assert!(args.is_err());
+}
+
+#[test]
+fn test_type_guards_conflict_with_ambient_declarations() {
+    for ambient in [
+        &["--dts"][..],
+        &["--declare-module", "@acme/cms"],
+        &["--declare-namespace", "Cms"],
+    ] {
+        let mut argv = vec![
+            "cs-ts",
+            "-k",
+            "test_key",
+            "-t",
+            "test_token",
+            "--type-guards",
+        ];
+        argv.extend_from_slice(ambient);
+        assert!(Args::try_parse_from(argv).is_err());
+    }
+}
+
+#[test]
+fn test_typescript_only_options_need_a_target_that_uses_them() {
+    for extra in [
+        &["--target", "rust", "--readonly"][..],
+        &["--target", "go", "--selects", "enum"],
+        &["--target", "python", "--editable-tags"],
+        &["--target", "json-schema", "--quotes", "double"],
+        // A custom TypeScript date type has no Zod schema
+        &["--target", "zod", "--date-type", "Dayjs"],
+        &[
+            "--target",
+            "typescript",
+            "--target",
+            "zod",
+            "--date-type",
+            "Dayjs",
+        ],
+    ] {
+        let args = parse_args("test_key", "test_token", extra);
+        assert!(matches!(App::new(args), Err(Error::Config(_))));
+    }
+    for extra in [
+        &["--target", "rust", "--target", "typescript", "--readonly"][..],
+        &["--target", "zod", "--quotes", "double", "--no-semicolons"],
+        &["--target", "zod", "--date-type", "branded"],
+        &["--target", "typescript", "--date-type", "Dayjs"],
+        &[
+            "--target",
+            "go",
+            "--nullability",
+            "nullable",
+            "--assume-empty",
+        ],
+    ] {
+        let args = parse_args("test_key", "test_token", extra);
+        assert!(App::new(args).is_ok());
+    }
+}
+
+#[test]
+fn test_check_module_names() {
+    let module = |file_name: &str| (file_name.to_string(), String::new());
+    assert!(App::check_module_names(&[module("page.ts"), module("index.ts")]).is_ok());
+    assert!(matches!(
+        App::check_module_names(&[module("builtins.ts"), module("index.ts"), module("index.ts")]),
+        Err(Error::Output(message)) if message.contains("index.ts")
+    ));
+}
+
+#[test]
+fn test_replace_extension() {
+    assert_eq!(App::replace_extension("types.ts", "zod.ts"), "types.zod.ts");
+    assert_eq!(
+        App::replace_extension("out/types.d.ts", "zod.ts"),
+        "out/types.zod.ts"
+    );
+    assert_eq!(App::replace_extension("types", "ts"), "types.ts");
+}
+
+#[test]
+fn test_go_package_must_be_an_identifier() {
+    let args = parse_args("test_key", "test_token", &[]);
+    assert_eq!(args.go_package, "contentstack");
+    let args = parse_args("test_key", "test_token", &["--go-package", "cms_v2"]);
+    assert_eq!(args.go_package, "cms_v2");
+    for package in ["", "_", "2cms", "my-cms", "github.com/acme/cms", "type"] {
+        let argv = [
+            "cs-ts",
+            "-k",
+            "test_key",
+            "-t",
+            "test_token",
+            "--go-package",
+            package,
+        ];
+        assert!(Args::try_parse_from(argv).is_err(), "{package:?}");
+    }
+}
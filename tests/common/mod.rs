@@ -0,0 +1,143 @@
+// Each test crate uses a different subset of these helpers
+#![allow(dead_code)]
+
+use clap::Parser;
+use cs_ts::args::Args;
+use cs_ts::generator::{Field, FieldConfig, GenerateConfig};
+
+/// Parses command line arguments the way the binary would, with the given credentials
+pub fn parse_args(api_key: &str, access_token: &str, extra: &[&str]) -> Args {
+    let mut argv = vec!["cs-ts", "-k", api_key, "-t", access_token];
+    argv.extend_from_slice(extra);
+    Args::try_parse_from(argv).expect("arguments should parse")
+}
+
+/// A content type exercising every kind of field the generators handle specially
+pub fn blog_post_definition() -> GenerateConfig {
+    GenerateConfig {
+        name: String::from("blog_post"),
+        title: Some(String::from("Blog Post")),
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::Text(FieldConfig {
+                name: String::from("title"),
+                path: vec![String::from("blog_post"), String::from("title")],
+                ..Default::default()
+            }),
+            Field::Text(FieldConfig {
+                name: String::from("status"),
+                optional: true,
+                enumeration: Some(vec![String::from("draft"), String::from("in review")]),
+                path: vec![String::from("blog_post"), String::from("status")],
+                ..Default::default()
+            }),
+            Field::Text(FieldConfig {
+                name: String::from("type"),
+                path: vec![String::from("blog_post"), String::from("type")],
+                ..Default::default()
+            }),
+            Field::Number(FieldConfig {
+                name: String::from("rating"),
+                enumeration: Some(vec![String::from("1"), String::from("2.5")]),
+                path: vec![String::from("blog_post"), String::from("rating")],
+                ..Default::default()
+            }),
+            Field::Number(FieldConfig {
+                name: String::from("Read-Time"),
+                display_name: Some(String::from("Read time")),
+                optional: true,
+                path: vec![String::from("blog_post"), String::from("Read-Time")],
+                ..Default::default()
+            }),
+            Field::Reference(FieldConfig {
+                name: String::from("author"),
+                multiple: true,
+                reference_to: vec![String::from("author")],
+                path: vec![String::from("blog_post"), String::from("author")],
+                ..Default::default()
+            }),
+            Field::Reference(FieldConfig {
+                name: String::from("related"),
+                optional: true,
+                reference_to: vec![String::from("blog_post"), String::from("page")],
+                path: vec![String::from("blog_post"), String::from("related")],
+                ..Default::default()
+            }),
+            Field::Global(FieldConfig {
+                name: String::from("seo"),
+                reference_to: vec![String::from("seo")],
+                path: vec![String::from("blog_post"), String::from("seo")],
+                ..Default::default()
+            }),
+            Field::Group(FieldConfig {
+                name: String::from("meta"),
+                multiple: true,
+                fields: vec![Field::Bool(FieldConfig {
+                    name: String::from("featured"),
+                    path: vec![
+                        String::from("blog_post"),
+                        String::from("meta"),
+                        String::from("featured"),
+                    ],
+                    ..Default::default()
+                })],
+                path: vec![String::from("blog_post"), String::from("meta")],
+                ..Default::default()
+            }),
+            Field::Blocks(FieldConfig {
+                name: String::from("sections"),
+                optional: true,
+                block_types: Some(vec![(
+                    String::from("hero"),
+                    vec![Field::File(FieldConfig {
+                        name: String::from("image"),
+                        path: vec![
+                            String::from("blog_post"),
+                            String::from("sections"),
+                            String::from("hero"),
+                            String::from("image"),
+                        ],
+                        ..Default::default()
+                    })],
+                )]),
+                path: vec![String::from("blog_post"), String::from("sections")],
+                ..Default::default()
+            }),
+        ],
+    }
+}
+
+/// A content type whose uids collide once turned into attribute names: two spellings of the
+/// same field, and a block with its own `metadata` next to the injected `_metadata`
+pub fn colliding_definition() -> GenerateConfig {
+    let text = |name: &str, path: &[&str]| {
+        Field::Text(FieldConfig {
+            name: String::from(name),
+            path: path.iter().map(|part| part.to_string()).collect(),
+            ..Default::default()
+        })
+    };
+    GenerateConfig {
+        name: String::from("landing_page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            text("hero-banner", &["landing_page", "hero-banner"]),
+            text("hero_banner", &["landing_page", "hero_banner"]),
+            Field::Blocks(FieldConfig {
+                name: String::from("sections"),
+                block_types: Some(vec![(
+                    String::from("cta"),
+                    vec![text(
+                        "metadata",
+                        &["landing_page", "sections", "cta", "metadata"],
+                    )],
+                )]),
+                path: vec![String::from("landing_page"), String::from("sections")],
+                ..Default::default()
+            }),
+        ],
+    }
+}
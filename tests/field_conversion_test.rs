@@ -1,18 +1,13 @@
+mod common;
+
+use common::parse_args;
 use cs_ts::app::App;
-use cs_ts::args::Args;
 use cs_ts::contentstack::response::*;
 use cs_ts::generator::Field;
-use cs_ts::region::Region;
+use cs_ts::generator::assign_paths;
 
 fn create_test_app() -> App {
-    let args = Args {
-        region: Region::NorthAmerica,
-        api_key: "test_key".to_string(),
-        access_token: "test_token".to_string(),
-        output: None,
-        prefix: None,
-        postfix: None,
-    };
+    let args = parse_args("test_key", "test_token", &[]);
     App::new(args).unwrap()
 }
 
@@ -21,6 +16,7 @@ fn test_convert_text_field() {
     let app = create_test_app();
     let schema = Schema {
         uid: "title".to_string(),
+        display_name: None,
         data_type: DataType::Text,
         schema: None,
         multiple: false,
@@ -47,6 +43,7 @@ fn test_convert_number_field() {
     let app = create_test_app();
     let schema = Schema {
         uid: "price".to_string(),
+        display_name: None,
         data_type: DataType::Number,
         schema: None,
         multiple: false,
@@ -73,6 +70,7 @@ fn test_convert_boolean_field() {
     let app = create_test_app();
     let schema = Schema {
         uid: "published".to_string(),
+        display_name: None,
         data_type: DataType::Boolean,
         schema: None,
         multiple: false,
@@ -99,6 +97,7 @@ fn test_convert_text_field_with_enumeration() {
     let app = create_test_app();
     let schema = Schema {
         uid: "status".to_string(),
+        display_name: None,
         data_type: DataType::Text,
         schema: None,
         multiple: false,
@@ -139,6 +138,7 @@ fn test_convert_reference_field() {
     let app = create_test_app();
     let schema = Schema {
         uid: "author".to_string(),
+        display_name: None,
         data_type: DataType::Reference,
         schema: None,
         multiple: false,
@@ -166,6 +166,7 @@ fn test_convert_reference_field_with_multiple_references() {
     let app = create_test_app();
     let schema = Schema {
         uid: "tags".to_string(),
+        display_name: None,
         data_type: DataType::Reference,
         schema: None,
         multiple: true,
@@ -199,10 +200,12 @@ fn test_convert_group_field() {
     let app = create_test_app();
     let schema = Schema {
         uid: "address".to_string(),
+        display_name: None,
         data_type: DataType::Group,
         schema: Some(vec![
             Schema {
                 uid: "street".to_string(),
+                display_name: None,
                 data_type: DataType::Text,
                 schema: None,
                 multiple: false,
@@ -214,6 +217,7 @@ fn test_convert_group_field() {
             },
             Schema {
                 uid: "city".to_string(),
+                display_name: None,
                 data_type: DataType::Text,
                 schema: None,
                 multiple: false,
@@ -249,12 +253,14 @@ fn test_convert_field_with_description() {
     let app = create_test_app();
     let schema = Schema {
         uid: "title".to_string(),
+        display_name: Some("Title".to_string()),
         data_type: DataType::Text,
         schema: None,
         multiple: false,
         mandatory: true,
         field_metadata: Some(FieldMetaData {
             description: Some("The title of the content".to_string()),
+            instruction: Some("Use sentence case".to_string()),
             ref_multiple: None,
         }),
         reference_to: None,
@@ -272,7 +278,51 @@ fn test_convert_field_with_description() {
                 config.description,
                 Some("The title of the content".to_string())
             );
+            assert_eq!(config.display_name, Some("Title".to_string()));
+            assert_eq!(config.instruction, Some("Use sentence case".to_string()));
         }
         _ => panic!("Expected Text field"),
     }
 }
+
+#[test]
+fn test_assign_paths() {
+    let app = create_test_app();
+    let schema = Schema {
+        uid: "sections".to_string(),
+        display_name: None,
+        data_type: DataType::Blocks,
+        schema: None,
+        multiple: true,
+        mandatory: false,
+        field_metadata: None,
+        reference_to: None,
+        blocks: Some(vec![Block {
+            uid: "hero".to_string(),
+            schema: vec![Schema {
+                uid: "title".to_string(),
+                display_name: None,
+                data_type: DataType::Text,
+                schema: None,
+                multiple: false,
+                mandatory: true,
+                field_metadata: None,
+                reference_to: None,
+                blocks: None,
+                enumuration: None,
+            }],
+        }]),
+        enumuration: None,
+    };
+
+    let mut fields = vec![app.convert_field(&schema).unwrap()];
+    assign_paths(&mut fields, &["page".to_string()]);
+
+    let config = fields[0].config();
+    assert_eq!(config.path, vec!["page", "sections"]);
+    let block_types = config.block_types.as_ref().unwrap();
+    assert_eq!(
+        block_types[0].1[0].config().path,
+        vec!["page", "sections", "hero", "title"]
+    );
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::{blog_post_definition, colliding_definition};
+use cs_ts::generator::{Generator, GeneratorOptions, GoGenerator};
+
+#[test]
+fn test_generate_struct() {
+    let generator = GoGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_interface(&blog_post_definition()),
+        "type BlogPostStatus string
+
+const (
+\tBlogPostStatusDraft BlogPostStatus = \"draft\"
+\tBlogPostStatusInReview BlogPostStatus = \"in review\"
+)
+
+type BlogPostRating float64
+
+const (
+\tBlogPostRating1 BlogPostRating = 1
+\tBlogPostRating25 BlogPostRating = 2.5
+)
+
+type BlogPostMeta struct {
+\tFeatured bool `json:\"featured\"`
+}
+
+type BlogPostSectionsHero struct {
+\tImage ContentstackFile `json:\"image\"`
+\tMetadata BlockMetadata `json:\"_metadata\"`
+}
+
+// BlogPostSections holds exactly one of its blocks
+type BlogPostSections struct {
+\tHero *BlogPostSectionsHero `json:\"hero,omitempty\"`
+}
+
+// Blog Post (`blog_post`)
+type BlogPost struct {
+\tTitle string `json:\"title\"`
+\tStatus *BlogPostStatus `json:\"status,omitempty\"`
+\tType string `json:\"type\"`
+\tRating BlogPostRating `json:\"rating\"`
+\t// Read time (`blog_post.Read-Time`)
+\tReadTime *float64 `json:\"Read-Time,omitempty\"`
+\tAuthor []Reference[Author] `json:\"author\"`
+\tRelated *Reference[json.RawMessage] `json:\"related,omitempty\"`
+\tSEO Seo `json:\"seo\"`
+\tMeta []BlogPostMeta `json:\"meta\"`
+\tSections []BlogPostSections `json:\"sections,omitempty\"`
+}
+"
+    );
+}
+
+#[test]
+fn test_affixed_names() {
+    let generator = GoGenerator::new(Some("Cs"), None);
+    let output = generator.generate_interface(&blog_post_definition());
+    assert!(output.contains("type CsBlogPost struct {"));
+    assert!(output.contains("\tSEO CsSeo `json:\"seo\"`"));
+    assert!(output.contains("\tHero *CsBlogPostSectionsHero `json:\"hero,omitempty\"`"));
+}
+
+#[test]
+fn test_colliding_field_names() {
+    let generator = GoGenerator::new(None, None);
+    let output = generator.generate_interface(&colliding_definition());
+    assert!(output.contains(
+        "\tHeroBanner string `json:\"hero-banner\"`\n\tHeroBanner2 string `json:\"hero_banner\"`\n"
+    ));
+    assert!(output.contains(
+        "\tMetadata2 string `json:\"metadata\"`\n\tMetadata BlockMetadata `json:\"_metadata\"`\n"
+    ));
+}
+
+#[test]
+fn test_generate_document() {
+    let generator = GoGenerator::with_options(GeneratorOptions::default(), "cms");
+    let document = generator.generate_document(&[], &[blog_post_definition()]);
+    assert!(document.starts_with(
+        "// Code generated by cs-ts. DO NOT EDIT.\n\npackage cms\n\nimport \"encoding/json\"\n\n"
+    ));
+    assert!(document.contains("type ContentstackFile struct {"));
+    assert!(document.contains("type Reference[T any] struct {"));
+    assert!(document.contains("type ContentstackJson = map[string]any\n"));
+    assert_eq!(generator.get_file_extension(), "go");
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::blog_post_definition;
+use cs_ts::generator::{
+    GenerateConfig, Generator, GeneratorOptions, JsonSchemaGenerator, Nullability,
+};
+use serde_json::{Value, json};
+
+fn parse(output: &str) -> Value {
+    serde_json::from_str(output).expect("output should be valid JSON")
+}
+
+#[test]
+fn test_generate_definition() {
+    let generator = JsonSchemaGenerator::new(None, None);
+    let schema = parse(&generator.generate_interface(&blog_post_definition()));
+    assert_eq!(schema["title"], "Blog Post");
+    assert_eq!(schema["type"], "object");
+    assert_eq!(
+        schema["required"],
+        json!(["title", "type", "rating", "author", "seo", "meta"])
+    );
+    assert_eq!(
+        schema["properties"]["Read-Time"],
+        json!({ "type": "number", "title": "Read time" })
+    );
+    assert_eq!(schema["properties"]["rating"], json!({ "enum": [1, 2.5] }));
+    let author = &schema["properties"]["author"]["items"];
+    assert_eq!(author["anyOf"][0], json!({ "$ref": "#/$defs/author" }));
+    assert_eq!(
+        author["anyOf"][1]["properties"]["_content_type_uid"],
+        json!({ "const": "author" })
+    );
+    assert_eq!(
+        schema["properties"]["seo"],
+        json!({ "$ref": "#/$defs/seo" })
+    );
+    assert_eq!(
+        schema["properties"]["_content_type_uid"],
+        json!({ "const": "blog_post" })
+    );
+
+    let hero = &schema["properties"]["sections"]["items"];
+    assert_eq!(hero["required"], json!(["hero"]));
+    assert_eq!(hero["additionalProperties"], json!(false));
+    assert_eq!(
+        hero["properties"]["hero"]["properties"]["image"],
+        json!({ "$ref": "#/$defs/ContentstackFile" })
+    );
+    assert_eq!(
+        hero["properties"]["hero"]["required"],
+        json!(["image", "_metadata"])
+    );
+}
+
+#[test]
+fn test_nullable_fields() {
+    let generator = JsonSchemaGenerator::with_options(GeneratorOptions {
+        nullability: Nullability::Nullable,
+        ..Default::default()
+    });
+    let schema = parse(&generator.generate_interface(&blog_post_definition()));
+    assert_eq!(
+        schema["required"],
+        json!([
+            "title",
+            "status",
+            "type",
+            "rating",
+            "Read-Time",
+            "author",
+            "related",
+            "seo",
+            "meta",
+            "sections"
+        ])
+    );
+    assert_eq!(
+        schema["properties"]["status"],
+        json!({ "anyOf": [{ "enum": ["draft", "in review"] }, { "type": "null" }] })
+    );
+}
+
+#[test]
+fn test_generate_document() {
+    let generator = JsonSchemaGenerator::new(None, None);
+    let seo = GenerateConfig {
+        name: String::from("seo"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    };
+    let document = parse(&generator.generate_document(&[seo], &[blog_post_definition()]));
+    assert_eq!(
+        document["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(document["anyOf"], json!([{ "$ref": "#/$defs/blog_post" }]));
+
+    let definitions: Vec<&String> = document["$defs"].as_object().unwrap().keys().collect();
+    assert_eq!(
+        definitions,
+        vec![
+            "ContentstackFile",
+            "PublishDetails",
+            "ContentstackLink",
+            "ContentstackJson",
+            "seo",
+            "blog_post"
+        ]
+    );
+    assert_eq!(document["$defs"]["seo"]["properties"], json!({}));
+    assert_eq!(
+        document["$defs"]["blog_post"]["properties"]["_content_type_uid"],
+        json!({ "const": "blog_post" })
+    );
+    assert_eq!(generator.get_file_extension(), "schema.json");
+}
@@ -0,0 +1,139 @@
+use cs_ts::generator::{Field, FieldConfig, GenerateConfig};
+use cs_ts::order::{Order, sort_fields};
+
+fn text(name: &str) -> Field {
+    Field::Text(FieldConfig {
+        name: name.to_string(),
+        ..Default::default()
+    })
+}
+
+fn reference(name: &str, to: &[&str]) -> Field {
+    Field::Reference(FieldConfig {
+        name: name.to_string(),
+        reference_to: to.iter().map(|uid| uid.to_string()).collect(),
+        ..Default::default()
+    })
+}
+
+fn definition(name: &str, fields: Vec<Field>) -> GenerateConfig {
+    GenerateConfig {
+        name: name.to_string(),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields,
+    }
+}
+
+fn names(definitions: &[GenerateConfig]) -> Vec<&str> {
+    definitions.iter().map(|d| d.name.as_str()).collect()
+}
+
+fn field_names(fields: &[Field]) -> Vec<&str> {
+    fields.iter().map(|f| f.config().name.as_str()).collect()
+}
+
+#[test]
+fn test_schema_order_keeps_api_order() {
+    let mut definitions = vec![
+        definition("page", vec![]),
+        definition("author", vec![]),
+        definition("blog_post", vec![]),
+    ];
+    Order::Schema.sort(&mut definitions);
+    assert_eq!(names(&definitions), vec!["page", "author", "blog_post"]);
+}
+
+#[test]
+fn test_alphabetical_order() {
+    let mut definitions = vec![
+        definition("page", vec![]),
+        definition("author", vec![]),
+        definition("blog_post", vec![]),
+    ];
+    Order::Alphabetical.sort(&mut definitions);
+    assert_eq!(names(&definitions), vec!["author", "blog_post", "page"]);
+}
+
+#[test]
+fn test_topological_order_places_dependencies_first() {
+    let mut definitions = vec![
+        definition("page", vec![reference("posts", &["blog_post"])]),
+        definition("blog_post", vec![reference("author", &["author"])]),
+        definition("author", vec![]),
+        definition("about", vec![]),
+    ];
+    Order::Topological.sort(&mut definitions);
+    assert_eq!(
+        names(&definitions),
+        vec!["about", "author", "blog_post", "page"]
+    );
+}
+
+#[test]
+fn test_topological_order_follows_nested_references() {
+    let mut definitions = vec![
+        definition(
+            "page",
+            vec![Field::Group(FieldConfig {
+                name: "hero".to_string(),
+                fields: vec![reference("cta", &["link_target"])],
+                ..Default::default()
+            })],
+        ),
+        definition("a_page", vec![reference("parent", &["page"])]),
+        definition("link_target", vec![]),
+    ];
+    Order::Topological.sort(&mut definitions);
+    assert_eq!(names(&definitions), vec!["link_target", "page", "a_page"]);
+}
+
+#[test]
+fn test_topological_order_handles_cycles_and_unknown_references() {
+    let mut definitions = vec![
+        definition("b", vec![reference("a", &["a"])]),
+        definition("a", vec![reference("b", &["b", "missing"])]),
+    ];
+    Order::Topological.sort(&mut definitions);
+    assert_eq!(names(&definitions), vec!["b", "a"]);
+
+    // The result does not depend on the input order
+    let mut reversed = vec![
+        definition("a", vec![reference("b", &["b", "missing"])]),
+        definition("b", vec![reference("a", &["a"])]),
+    ];
+    Order::Topological.sort(&mut reversed);
+    assert_eq!(names(&reversed), vec!["b", "a"]);
+}
+
+#[test]
+fn test_sort_fields_recursively() {
+    let mut fields = vec![
+        text("title"),
+        Field::Group(FieldConfig {
+            name: "address".to_string(),
+            fields: vec![text("street"), text("city")],
+            ..Default::default()
+        }),
+        Field::Blocks(FieldConfig {
+            name: "blocks".to_string(),
+            block_types: Some(vec![
+                ("hero".to_string(), vec![text("title"), text("image")]),
+                ("banner".to_string(), vec![]),
+            ]),
+            ..Default::default()
+        }),
+    ];
+    sort_fields(&mut fields);
+    assert_eq!(field_names(&fields), vec!["address", "blocks", "title"]);
+    assert_eq!(
+        field_names(&fields[0].config().fields),
+        vec!["city", "street"]
+    );
+
+    let block_types = fields[1].config().block_types.as_ref().unwrap();
+    assert_eq!(block_types[0].0, "banner");
+    assert_eq!(block_types[1].0, "hero");
+    assert_eq!(field_names(&block_types[1].1), vec!["image", "title"]);
+}
@@ -0,0 +1,211 @@
+mod common;
+
+use common::{blog_post_definition, colliding_definition};
+use cs_ts::generator::PythonStyle;
+use cs_ts::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, PythonGenerator,
+};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_generate_model() {
+    let generator = PythonGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_interface(&blog_post_definition()),
+        r#"class BlogPostMeta(ContentstackModel):
+    featured: bool
+
+
+class BlogPostSectionsHero(ContentstackModel):
+    image: ContentstackFile
+    metadata: BlockMetadata = Field(alias="_metadata")
+
+
+class BlogPostSectionsHeroBlock(ContentstackModel):
+    hero: BlogPostSectionsHero
+
+
+class BlogPost(ContentstackModel):
+    """Blog Post (`blog_post`)"""
+
+    title: str
+    status: Optional[Literal["draft", "in review"]] = None
+    type: str
+    rating: float
+    # Read time (`blog_post.Read-Time`)
+    read_time: Optional[float] = Field(default=None, alias="Read-Time")
+    author: list[Annotated[Union["Author", ContentstackReference], Field(union_mode="left_to_right")]]
+    related: Optional[Annotated[Union["BlogPost", "Page", ContentstackReference], Field(union_mode="left_to_right")]] = None
+    seo: "Seo"
+    meta: list[BlogPostMeta]
+    sections: Optional[list[BlogPostSectionsHeroBlock]] = None
+    content_type_uid: Literal["blog_post"] = Field(default="blog_post", alias="_content_type_uid")
+"#
+    );
+}
+
+#[test]
+fn test_generate_typed_dict() {
+    let generator =
+        PythonGenerator::with_options(GeneratorOptions::default(), PythonStyle::TypedDict);
+    let output = generator.generate_interface(&blog_post_definition());
+    assert!(output.contains(
+        "class BlogPostSectionsHero(TypedDict):
+    image: ContentstackFile
+    _metadata: BlockMetadata
+"
+    ));
+    // `Read-Time` is not an identifier, so the functional syntax is used
+    assert!(output.ends_with(
+        r#"# Blog Post (`blog_post`)
+BlogPost = TypedDict(
+    "BlogPost",
+    {
+        "title": str,
+        "status": NotRequired[Literal["draft", "in review"]],
+        "type": str,
+        "rating": float,
+        # Read time (`blog_post.Read-Time`)
+        "Read-Time": NotRequired[float],
+        "author": list[Union["Author", ContentstackReference]],
+        "related": NotRequired[Union["BlogPost", "Page", ContentstackReference]],
+        "seo": "Seo",
+        "meta": list[BlogPostMeta],
+        "sections": NotRequired[list[BlogPostSectionsHeroBlock]],
+        "_content_type_uid": NotRequired[Literal["blog_post"]],
+    },
+)
+"#
+    ));
+}
+
+#[test]
+fn test_docstring_ending_in_a_quote() {
+    let generator = PythonGenerator::new(None, None);
+    let definition = GenerateConfig {
+        description: Some(String::from(r#"Says "hi""#)),
+        ..titled_definition("greeting")
+    };
+    assert!(generator.generate_interface(&definition).starts_with(
+        r#"class Greeting(ContentstackModel):
+    """
+    Says "hi"
+    """
+"#
+    ));
+}
+
+#[test]
+fn test_colliding_attribute_names() {
+    let generator = PythonGenerator::new(None, None);
+    let output = generator.generate_interface(&colliding_definition());
+    assert!(output.contains(
+        "    hero_banner: str = Field(alias=\"hero-banner\")\n    hero_banner_2: str = Field(alias=\"hero_banner\")\n"
+    ));
+    assert!(output.contains(
+        "    metadata_2: str = Field(alias=\"metadata\")\n    metadata: BlockMetadata = Field(alias=\"_metadata\")\n"
+    ));
+}
+
+#[test]
+fn test_integer_number_select() {
+    let generator = PythonGenerator::new(None, None);
+    let config = FieldConfig {
+        name: String::from("rating"),
+        enumeration: Some(vec![String::from("1"), String::from("2")]),
+        ..Default::default()
+    };
+    assert_eq!(generator.generate_number(&config), "rating: Literal[1, 2]");
+}
+
+#[test]
+fn test_generate_document() {
+    let generator = PythonGenerator::new(None, None);
+    let document = generator.generate_document(&[], &[blog_post_definition()]);
+    assert!(document.starts_with(
+        "from typing import Annotated, Any, Literal, Optional, Union\n\nfrom pydantic import BaseModel, ConfigDict, Field\n"
+    ));
+    assert!(document.contains(
+        "class ContentstackModel(BaseModel):\n    model_config = ConfigDict(populate_by_name=True)\n"
+    ));
+    assert!(document.contains("    version: int = Field(alias=\"_version\")\n"));
+    assert!(document.ends_with("\n\nBlogPost.model_rebuild()\n"));
+    assert_eq!(generator.get_file_extension(), "py");
+}
+
+/// Whether the generated models can be run here
+fn has_pydantic() -> bool {
+    Command::new("python3")
+        .args(["-c", "import pydantic"])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Definition with nothing but a title, standing in for the targets of the fixture's references
+fn titled_definition(name: &str) -> GenerateConfig {
+    GenerateConfig {
+        name: String::from(name),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("title"),
+            ..Default::default()
+        })],
+    }
+}
+
+#[test]
+fn test_models_tell_references_apart() {
+    let generator = PythonGenerator::new(None, None);
+    let seo = GenerateConfig {
+        fields: Vec::new(),
+        ..titled_definition("seo")
+    };
+    let document = generator.generate_document(
+        &[seo],
+        &[
+            blog_post_definition(),
+            titled_definition("page"),
+            titled_definition("author"),
+        ],
+    );
+    // Global fields are embedded in entries, so only content types carry the uid
+    assert!(!document.contains("Literal[\"seo\"]"));
+    assert!(document.contains(
+        "content_type_uid: Literal[\"page\"] = Field(default=\"page\", alias=\"_content_type_uid\")"
+    ));
+
+    let script = r#"
+import sys
+
+namespace = {}
+exec(sys.stdin.read(), namespace)
+BlogPost = namespace["BlogPost"]
+post = {"title": "Hello", "type": "news", "rating": 1, "seo": {}, "meta": [], "author": []}
+resolved = BlogPost.model_validate(
+    {**post, "related": {"uid": "p1", "_content_type_uid": "page", "title": "Home"}}
+)
+assert type(resolved.related).__name__ == "Page", resolved.related
+assert resolved.related.title == "Home"
+stub = BlogPost.model_validate({**post, "related": {"uid": "p1", "_content_type_uid": "page"}})
+assert type(stub.related).__name__ == "ContentstackReference", stub.related
+"#;
+    if !has_pydantic() {
+        eprintln!("skipping: python3 with pydantic is not available");
+        return;
+    }
+    let mut child = Command::new("python3")
+        .args(["-c", script])
+        .stdin(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(document.as_bytes())
+        .unwrap();
+    assert!(child.wait().unwrap().success());
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{blog_post_definition, colliding_definition};
+use cs_ts::generator::{Generator, RustGenerator};
+
+#[test]
+fn test_generate_struct() {
+    let generator = RustGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_interface(&blog_post_definition()),
+        r#"#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum BlogPostStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "in review")]
+    InReview,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BlogPostRelated {
+    Stub(ReferenceStub),
+    Entry(BlogPostRelatedEntry),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "_content_type_uid")]
+pub enum BlogPostRelatedEntry {
+    #[serde(rename = "blog_post")]
+    BlogPost(Box<BlogPost>),
+    #[serde(rename = "page")]
+    Page(Box<Page>),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlogPostMeta {
+    pub featured: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlogPostSectionsHero {
+    pub image: ContentstackFile,
+    #[serde(rename = "_metadata")]
+    pub metadata: BlockMetadata,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum BlogPostSections {
+    #[serde(rename = "hero")]
+    Hero(BlogPostSectionsHero),
+}
+
+/// Blog Post (`blog_post`)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlogPost {
+    pub title: String,
+    pub status: Option<BlogPostStatus>,
+    pub r#type: String,
+    pub rating: f64,
+    /// Read time (`blog_post.Read-Time`)
+    #[serde(rename = "Read-Time")]
+    pub read_time: Option<f64>,
+    pub author: Vec<Reference<Author>>,
+    pub related: Option<BlogPostRelated>,
+    pub seo: Seo,
+    pub meta: Vec<BlogPostMeta>,
+    pub sections: Option<Vec<BlogPostSections>>,
+}
+"#
+    );
+}
+
+#[test]
+fn test_affixed_names() {
+    let generator = RustGenerator::new(Some("Cs"), None);
+    let output = generator.generate_interface(&blog_post_definition());
+    assert!(output.contains("pub struct CsBlogPost {"));
+    assert!(output.contains("pub seo: CsSeo,"));
+    assert!(output.contains("Hero(CsBlogPostSectionsHero),"));
+}
+
+#[test]
+fn test_colliding_field_names() {
+    let generator = RustGenerator::new(None, None);
+    let output = generator.generate_interface(&colliding_definition());
+    assert!(output.contains(
+        "#[serde(rename = \"hero-banner\")]\n    pub hero_banner: String,\n    #[serde(rename = \"hero_banner\")]\n    pub hero_banner_2: String,\n"
+    ));
+    assert!(output.contains(
+        "#[serde(rename = \"metadata\")]\n    pub metadata_2: String,\n    #[serde(rename = \"_metadata\")]\n    pub metadata: BlockMetadata,\n"
+    ));
+}
+
+#[test]
+fn test_generate_document() {
+    let generator = RustGenerator::new(None, None);
+    let document = generator.generate_document(&[], &[blog_post_definition()]);
+    assert!(document.starts_with("use serde::{Deserialize, Serialize};\n\n"));
+    assert!(document.contains("pub struct ContentstackFile {"));
+    assert!(document.contains("#[serde(untagged)]\npub enum Reference<T> {"));
+    assert!(document.contains("#[serde(deny_unknown_fields)]\npub struct ReferenceStub {"));
+    assert!(document.contains("pub type ContentstackJson = serde_json::Value;"));
+    assert!(document.ends_with("pub sections: Option<Vec<BlogPostSections>>,\n}\n"));
+    assert_eq!(generator.get_file_extension(), "rs");
+}
@@ -1,5 +1,70 @@
-use cs_ts::generator::typescript::TypescriptGenerator;
-use cs_ts::generator::{FieldConfig, Generator};
+use cs_ts::generator::typescript::{
+    Ambient, BuiltinsMode, DateType, DeclarationStyle, Formatting, Indent, QuoteStyle,
+    ReferenceStyle, SelectStyle, TypescriptGenerator, TypescriptOptions,
+};
+use cs_ts::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, Nullability,
+};
+use proptest::prelude::*;
+
+/// Whether `name` is an ECMAScript IdentifierName, per the `ID_Start`/`ID_Continue` grammar
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c == '$' || c == '_' || unicode_ident::is_xid_start(c))
+        && chars.all(|c| {
+            c == '$' || c == '\u{200c}' || c == '\u{200d}' || unicode_ident::is_xid_continue(c)
+        })
+}
+
+/// Parses a single-quoted TypeScript string literal, returning the decoded value
+/// and the remaining input. Rejects raw line terminators like a TS parser would.
+fn parse_ts_string(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.char_indices();
+    if chars.next()?.1 != '\'' {
+        return None;
+    }
+    let mut value = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\'' => return Some((value, &input[index + 1..])),
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => return None,
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'b' => value.push('\u{8}'),
+                'f' => value.push('\u{c}'),
+                'v' => value.push('\u{b}'),
+                '0' => value.push('\0'),
+                'u' => {
+                    let hex: String = (0..4)
+                        .map(|_| chars.next().map(|(_, c)| c))
+                        .collect::<Option<_>>()?;
+                    value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                other => value.push(other),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+fn parse_ts_string_union(input: &str) -> Option<Vec<String>> {
+    let mut values = Vec::new();
+    let mut rest = input;
+    loop {
+        let (value, remaining) = parse_ts_string(rest)?;
+        values.push(value);
+        match remaining.strip_prefix(" | ") {
+            Some(next) => rest = next,
+            None if remaining.is_empty() => return Some(values),
+            None => return None,
+        }
+    }
+}
 
 #[test]
 fn test_generate_text() {
@@ -97,3 +162,1647 @@ fn test_generate_text() {
         "/** Document */\ntitle?: 'one' | 'two';"
     );
 }
+
+#[test]
+fn test_generate_field_with_non_identifier_uid() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("123"),
+            ..Default::default()
+        }),
+        "'123': string;"
+    );
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("2nd_title"),
+            optional: true,
+            ..Default::default()
+        }),
+        "'2nd_title'?: string;"
+    );
+    assert_eq!(
+        generator.generate_bool(&FieldConfig {
+            name: String::from("is-active"),
+            ..Default::default()
+        }),
+        "'is-active': boolean;"
+    );
+    assert_eq!(
+        generator.generate_bool(&FieldConfig {
+            name: String::from("default"),
+            ..Default::default()
+        }),
+        "'default': boolean;"
+    );
+}
+
+#[test]
+fn test_generate_field_with_unicode_uid() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("überschrift"),
+            ..Default::default()
+        }),
+        "überschrift: string;"
+    );
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("título-principal"),
+            ..Default::default()
+        }),
+        "'título-principal': string;"
+    );
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("x²"),
+            ..Default::default()
+        }),
+        "'x²': string;"
+    );
+}
+
+#[test]
+fn test_generate_blocks_with_non_identifier_uid() {
+    let generator = TypescriptGenerator::new(None, None);
+    let output = generator.generate_blocks(&FieldConfig {
+        name: String::from("page-sections"),
+        block_types: Some(vec![(
+            String::from("hero-banner"),
+            vec![Field::Text(FieldConfig {
+                name: String::from("title"),
+                ..Default::default()
+            })],
+        )]),
+        ..Default::default()
+    });
+    assert!(output.starts_with("'page-sections': Array<{"));
+    assert!(output.contains("  'hero-banner': {\n    title: string;\n"));
+}
+
+#[test]
+fn test_generate_text_escapes_enumeration() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("quote"),
+            enumeration: Some(vec![
+                String::from("it's"),
+                String::from("back\\slash"),
+                String::from("two\nlines"),
+            ]),
+            ..Default::default()
+        }),
+        "quote: 'it\\'s' | 'back\\\\slash' | 'two\\nlines';"
+    );
+}
+
+#[test]
+fn test_generate_number_enumeration() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_number(&FieldConfig {
+            name: String::from("rating"),
+            enumeration: Some(vec![String::from("1"), String::from("2")]),
+            ..Default::default()
+        }),
+        "rating: 1 | 2;"
+    );
+}
+
+#[test]
+fn test_generate_interface_escapes_content_type_uid() {
+    let generator = TypescriptGenerator::new(None, None);
+    let output = generator.generate_interface(&GenerateConfig {
+        name: String::from("author's_page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    });
+    assert!(output.contains("  _content_type_uid: 'author\\'s_page';\n"));
+}
+
+proptest! {
+    #[test]
+    fn prop_enumeration_literals_round_trip(choices in prop::collection::vec(any::<String>(), 1..5)) {
+        let generator = TypescriptGenerator::new(None, None);
+        let output = generator.generate_text(&FieldConfig {
+            name: String::from("choice"),
+            enumeration: Some(choices.clone()),
+            ..Default::default()
+        });
+        let union = output
+            .strip_prefix("choice: ")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .expect("field should be rendered as `choice: ...;`");
+        prop_assert_eq!(parse_ts_string_union(union), Some(choices));
+    }
+
+    #[test]
+    fn prop_content_type_uid_round_trips(uid in any::<String>()) {
+        let generator = TypescriptGenerator::new(None, None);
+        let output = generator.generate_interface(&GenerateConfig {
+            name: uid.clone(),
+            title: None,
+            description: None,
+            editor_url: None,
+            fields: vec![],
+        });
+        let line = output
+            .lines()
+            .find_map(|line| line.strip_prefix("  _content_type_uid: "))
+            .expect("interface should contain _content_type_uid");
+        prop_assert_eq!(parse_ts_string(line), Some((uid, ";")));
+    }
+
+    #[test]
+    fn prop_quoted_property_names_round_trip(name in any::<String>()) {
+        let generator = TypescriptGenerator::new(None, None);
+        let output = generator.generate_bool(&FieldConfig {
+            name: name.clone(),
+            ..Default::default()
+        });
+        let key = output.strip_suffix(": boolean;").expect("field should end with its type");
+        if key.starts_with('\'') {
+            prop_assert_eq!(parse_ts_string(key), Some((name, "")));
+        } else {
+            prop_assert!(is_identifier(key), "unquoted key {:?} is not an identifier", key);
+            prop_assert_eq!(key, name);
+        }
+    }
+}
+
+#[test]
+fn test_get_builtins() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.get_builtins(),
+        "export interface ContentstackFile {
+  uid: string;
+  created_at: string;
+  updated_at: string;
+  created_by: string;
+  updated_by: string;
+  content_type: string;
+  file_size: string;
+  tags: string[];
+  filename: string;
+  url: string;
+  ACL: any[] | object;
+  is_dir: boolean;
+  parent_uid: string;
+  _version: number;
+  title: string;
+  _metadata?: object;
+  description?: string;
+  dimension?: {
+    height: number;
+    width: number;
+  };
+  publish_details: PublishDetails;
+}
+
+export interface PublishDetails {
+  environment: string;
+  locale: string;
+  time: string;
+  user: string;
+}
+
+export interface ContentstackLink {
+  title: string;
+  href: string;
+}
+
+export interface ContentstackJson {
+  [key: string]: any;
+}
+"
+    );
+}
+
+#[test]
+fn test_affixes_apply_to_references_and_global_fields() {
+    let generator = TypescriptGenerator::new(Some("CS"), Some("Type"));
+    assert_eq!(
+        generator.generate_reference(&FieldConfig {
+            name: String::from("author"),
+            reference_to: vec![String::from("person")],
+            ..Default::default()
+        }),
+        "author: CSPersonType | { _content_type_uid: 'person'; uid: string };"
+    );
+    assert_eq!(
+        generator.generate_global_field(&FieldConfig {
+            name: String::from("seo"),
+            reference_to: vec![String::from("seo_metadata")],
+            ..Default::default()
+        }),
+        "seo: CSSeoMetadataType;"
+    );
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("blog_post"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    });
+    assert!(interface.starts_with("export interface CSBlogPostType {"));
+}
+
+#[test]
+fn test_builtins_are_not_affixed_by_default() {
+    let generator = TypescriptGenerator::new(Some("CS"), None);
+    assert_eq!(
+        generator.generate_file(&FieldConfig {
+            name: String::from("image"),
+            ..Default::default()
+        }),
+        "image: ContentstackFile;"
+    );
+    assert!(
+        generator
+            .get_builtins()
+            .contains("export interface ContentstackFile {")
+    );
+}
+
+#[test]
+fn test_affix_builtins() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            prefix: String::from("CS"),
+            postfix: String::from("Type"),
+            affix_builtins: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_file(&FieldConfig {
+            name: String::from("image"),
+            ..Default::default()
+        }),
+        "image: CSContentstackFileType;"
+    );
+    assert_eq!(
+        generator.generate_link(&FieldConfig {
+            name: String::from("link"),
+            ..Default::default()
+        }),
+        "link: CSContentstackLinkType;"
+    );
+    assert_eq!(
+        generator.generate_json(&FieldConfig {
+            name: String::from("data"),
+            ..Default::default()
+        }),
+        "data: CSContentstackJsonType;"
+    );
+    let builtins = generator.get_builtins();
+    assert!(builtins.contains("export interface CSContentstackFileType {"));
+    assert!(builtins.contains("  publish_details: CSPublishDetailsType;"));
+    assert!(builtins.contains("export interface CSPublishDetailsType {"));
+    assert!(builtins.contains("export interface CSContentstackLinkType {"));
+    assert!(builtins.contains("export interface CSContentstackJsonType {"));
+}
+
+#[test]
+fn test_generic_references() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        references: ReferenceStyle::Generic,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_reference(&FieldConfig {
+            name: String::from("author"),
+            reference_to: vec![String::from("person")],
+            ..Default::default()
+        }),
+        "author: Resolved extends true ? Person : { _content_type_uid: 'person'; uid: string };"
+    );
+    assert_eq!(
+        generator.generate_reference(&FieldConfig {
+            name: String::from("related"),
+            multiple: true,
+            reference_to: vec![String::from("page"), String::from("post")],
+            ..Default::default()
+        }),
+        "related: Array<Resolved extends true ? Page | Post : { _content_type_uid: 'page'; uid: string } | { _content_type_uid: 'post'; uid: string }>;"
+    );
+    assert_eq!(
+        generator.generate_global_field(&FieldConfig {
+            name: String::from("seo"),
+            reference_to: vec![String::from("seo")],
+            ..Default::default()
+        }),
+        "seo: Seo<Resolved>;"
+    );
+}
+
+#[test]
+fn test_generic_interface_with_resolved_alias() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            prefix: String::from("CS"),
+            ..Default::default()
+        },
+        references: ReferenceStyle::Generic,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("blog_post"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Reference(FieldConfig {
+            name: String::from("author"),
+            reference_to: vec![String::from("person")],
+            ..Default::default()
+        })],
+    });
+    assert_eq!(
+        interface,
+        "export interface CSBlogPost<Resolved extends boolean = false> {
+  _content_type_uid: 'blog_post';
+  author: Resolved extends true ? CSPerson : { _content_type_uid: 'person'; uid: string };
+}
+export type CSBlogPostResolved = CSBlogPost<true>;"
+    );
+}
+
+#[test]
+fn test_interface_declaration_style() {
+    let generator = TypescriptGenerator::new(None, None);
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("author"),
+        title: None,
+        description: Some(String::from("An author")),
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("name"),
+            ..Default::default()
+        })],
+    });
+    assert_eq!(
+        interface,
+        "/** An author */
+export interface Author {
+  _content_type_uid: 'author';
+  name: string;
+}"
+    );
+}
+
+#[test]
+fn test_type_declaration_style() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        declaration: DeclarationStyle::Type,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("author"),
+        title: None,
+        description: Some(String::from("An author")),
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("name"),
+            ..Default::default()
+        })],
+    });
+    assert_eq!(
+        interface,
+        "/** An author */
+export type Author = {
+  _content_type_uid: 'author';
+  name: string;
+};"
+    );
+
+    let builtins = generator.get_builtins();
+    assert!(!builtins.contains("interface"));
+    assert!(builtins.contains("export type ContentstackFile = {\n"));
+    assert!(builtins.contains("export type PublishDetails = {\n"));
+    assert!(builtins.contains("export type ContentstackLink = {\n"));
+    assert!(builtins.contains("export type ContentstackJson = {\n  [key: string]: any;\n};\n"));
+}
+
+#[test]
+fn test_type_declaration_style_with_generic_references() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        declaration: DeclarationStyle::Type,
+        references: ReferenceStyle::Generic,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    });
+    assert_eq!(
+        interface,
+        "export type Page<Resolved extends boolean = false> = {
+  _content_type_uid: 'page';
+};
+export type PageResolved = Page<true>;"
+    );
+}
+
+fn select_definition() -> GenerateConfig {
+    GenerateConfig {
+        name: String::from("blog_post"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::Text(FieldConfig {
+                name: String::from("status"),
+                enumeration: Some(vec![String::from("draft"), String::from("in review")]),
+                path: vec![String::from("blog_post"), String::from("status")],
+                ..Default::default()
+            }),
+            Field::Group(FieldConfig {
+                name: String::from("layout"),
+                path: vec![String::from("blog_post"), String::from("layout")],
+                fields: vec![Field::Number(FieldConfig {
+                    name: String::from("columns"),
+                    multiple: true,
+                    enumeration: Some(vec![String::from("1"), String::from("2")]),
+                    path: vec![
+                        String::from("blog_post"),
+                        String::from("layout"),
+                        String::from("columns"),
+                    ],
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }),
+        ],
+    }
+}
+
+#[test]
+fn test_inline_selects() {
+    let generator = TypescriptGenerator::new(None, None);
+    let interface = generator.generate_interface(&select_definition());
+    assert!(interface.starts_with("export interface BlogPost {"));
+    assert!(interface.contains("  status: 'draft' | 'in review';\n"));
+    assert!(interface.contains("    columns: Array<1 | 2>;\n"));
+}
+
+#[test]
+fn test_const_selects() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        selects: SelectStyle::Const,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_interface(&select_definition()),
+        "export const BlogPostStatusChoices = ['draft', 'in review'] as const;
+export type BlogPostStatus = typeof BlogPostStatusChoices[number];
+
+export const BlogPostLayoutColumnsChoices = [1, 2] as const;
+export type BlogPostLayoutColumns = typeof BlogPostLayoutColumnsChoices[number];
+
+export interface BlogPost {
+  _content_type_uid: 'blog_post';
+  status: BlogPostStatus;
+  layout: {
+    columns: Array<BlogPostLayoutColumns>;
+  };
+}"
+    );
+}
+
+#[test]
+fn test_enum_selects() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            prefix: String::from("CS"),
+            ..Default::default()
+        },
+        selects: SelectStyle::Enum,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_interface(&select_definition()),
+        "export enum CSBlogPostStatus {
+  Draft = 'draft',
+  InReview = 'in review',
+}
+
+export enum CSBlogPostLayoutColumns {
+  _1 = 1,
+  _2 = 2,
+}
+
+export interface CSBlogPost {
+  _content_type_uid: 'blog_post';
+  status: CSBlogPostStatus;
+  layout: {
+    columns: Array<CSBlogPostLayoutColumns>;
+  };
+}"
+    );
+}
+
+#[test]
+fn test_enum_select_member_names_are_unique() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        selects: SelectStyle::Enum,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("theme"),
+            enumeration: Some(vec![
+                String::from("dark-mode"),
+                String::from("dark_mode"),
+                String::from("it's"),
+                String::from(""),
+            ]),
+            ..Default::default()
+        })],
+    });
+    assert!(interface.starts_with(
+        "export enum Theme {
+  DarkMode = 'dark-mode',
+  DarkMode2 = 'dark_mode',
+  Its = 'it\\'s',
+  _ = '',
+}
+"
+    ));
+}
+
+#[test]
+fn test_date_type_parsing() {
+    assert_eq!("string".parse::<DateType>(), Ok(DateType::String));
+    assert_eq!("date".parse::<DateType>(), Ok(DateType::Date));
+    assert_eq!("Date".parse::<DateType>(), Ok(DateType::Date));
+    assert_eq!("branded".parse::<DateType>(), Ok(DateType::Branded));
+    assert_eq!(
+        "Temporal.Instant".parse::<DateType>(),
+        Ok(DateType::Custom(String::from("Temporal.Instant")))
+    );
+    assert!("".parse::<DateType>().is_err());
+}
+
+#[test]
+fn test_date_type_mapping() {
+    let date_field = FieldConfig {
+        name: String::from("published_at"),
+        multiple: true,
+        ..Default::default()
+    };
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        date_type: DateType::Date,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_date(&date_field),
+        "published_at: Array<Date>;"
+    );
+    assert!(generator.get_builtins().contains("  created_at: Date;\n"));
+    assert!(generator.get_builtins().contains("  time: Date;\n"));
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        date_type: DateType::Custom(String::from("Dayjs")),
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_date(&date_field),
+        "published_at: Array<Dayjs>;"
+    );
+}
+
+#[test]
+fn test_branded_date_type() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            prefix: String::from("CS"),
+            affix_builtins: true,
+            ..Default::default()
+        },
+        date_type: DateType::Branded,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_date(&FieldConfig {
+            name: String::from("published_at"),
+            ..Default::default()
+        }),
+        "published_at: CSIsoDateString;"
+    );
+    let builtins = generator.get_builtins();
+    assert!(builtins.contains("  updated_at: CSIsoDateString;\n"));
+    assert!(builtins.ends_with(
+        "export type CSIsoDateString = string & { readonly __brand: 'IsoDateString' };\n"
+    ));
+}
+
+#[test]
+fn test_number_and_file_type_mapping() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        number_type: Some(String::from("Decimal")),
+        file_type: Some(String::from("Asset")),
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_number(&FieldConfig {
+            name: String::from("price"),
+            ..Default::default()
+        }),
+        "price: Decimal;"
+    );
+    assert_eq!(
+        generator.generate_number(&FieldConfig {
+            name: String::from("rating"),
+            enumeration: Some(vec![String::from("1"), String::from("5")]),
+            ..Default::default()
+        }),
+        "rating: 1 | 5;"
+    );
+    assert_eq!(
+        generator.generate_file(&FieldConfig {
+            name: String::from("images"),
+            multiple: true,
+            ..Default::default()
+        }),
+        "images: Array<Asset>;"
+    );
+}
+
+#[test]
+fn test_nullability_modes() {
+    let optional = FieldConfig {
+        name: String::from("summary"),
+        optional: true,
+        ..Default::default()
+    };
+    let mandatory = FieldConfig {
+        name: String::from("summary"),
+        optional: false,
+        ..Default::default()
+    };
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            nullability: Nullability::Nullable,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_text(&optional),
+        "summary: string | null;"
+    );
+    assert_eq!(generator.generate_text(&mandatory), "summary: string;");
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            nullability: Nullability::OptionalNullable,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_text(&optional),
+        "summary?: string | null;"
+    );
+    assert_eq!(generator.generate_text(&mandatory), "summary: string;");
+}
+
+#[test]
+fn test_nullable_group_and_multiple_fields() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            nullability: Nullability::Nullable,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_file(&FieldConfig {
+            name: String::from("images"),
+            optional: true,
+            multiple: true,
+            ..Default::default()
+        }),
+        "images: Array<ContentstackFile> | null;"
+    );
+    assert_eq!(
+        generator.generate_group(&FieldConfig {
+            name: String::from("seo"),
+            optional: true,
+            fields: vec![Field::Text(FieldConfig {
+                name: String::from("title"),
+                optional: true,
+                ..Default::default()
+            })],
+            ..Default::default()
+        }),
+        "seo: {\n  title: string | null;\n} | null;"
+    );
+}
+
+#[test]
+fn test_nullable_generic_reference_is_parenthesized() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            nullability: Nullability::OptionalNullable,
+            ..Default::default()
+        },
+        references: ReferenceStyle::Generic,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_reference(&FieldConfig {
+            name: String::from("author"),
+            optional: true,
+            reference_to: vec![String::from("person")],
+            ..Default::default()
+        }),
+        "author?: (Resolved extends true ? Person : { _content_type_uid: 'person'; uid: string }) | null;"
+    );
+}
+
+#[test]
+fn test_assume_empty() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            assume_empty: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::Text(FieldConfig {
+                name: String::from("title"),
+                path: vec![String::from("page"), String::from("title")],
+                entry_title: true,
+                ..Default::default()
+            }),
+            Field::Text(FieldConfig {
+                name: String::from("url"),
+                path: vec![String::from("page"), String::from("url")],
+                ..Default::default()
+            }),
+            Field::Group(FieldConfig {
+                name: String::from("card"),
+                path: vec![String::from("page"), String::from("card")],
+                fields: vec![Field::Text(FieldConfig {
+                    name: String::from("title"),
+                    path: vec![
+                        String::from("page"),
+                        String::from("card"),
+                        String::from("title"),
+                    ],
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }),
+        ],
+    });
+    assert!(interface.contains("\n  title: string;\n"));
+    assert!(interface.contains("\n  url?: string;\n"));
+    assert!(interface.contains("\n  card?: {\n    title?: string;\n  };\n"));
+}
+
+#[test]
+fn test_assume_empty_global_field_title() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            assume_empty: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("title"),
+            path: vec![String::from("seo"), String::from("title")],
+            ..Default::default()
+        }),
+        "title?: string;"
+    );
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("title"),
+            ..Default::default()
+        }),
+        "title?: string;"
+    );
+}
+
+#[test]
+fn test_readonly_fields() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        readonly: true,
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("tags"),
+            optional: true,
+            multiple: true,
+            ..Default::default()
+        }),
+        "readonly tags?: ReadonlyArray<string>;"
+    );
+    assert_eq!(
+        generator.generate_reference(&FieldConfig {
+            name: String::from("author"),
+            reference_to: vec![String::from("person")],
+            ..Default::default()
+        }),
+        "readonly author: Person | { readonly _content_type_uid: 'person'; readonly uid: string };"
+    );
+}
+
+#[test]
+fn test_readonly_interface_is_applied_recursively() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        readonly: true,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::Group(FieldConfig {
+                name: String::from("links"),
+                multiple: true,
+                fields: vec![Field::Link(FieldConfig {
+                    name: String::from("link"),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }),
+            Field::Blocks(FieldConfig {
+                name: String::from("sections"),
+                block_types: Some(vec![(
+                    String::from("hero"),
+                    vec![Field::Text(FieldConfig {
+                        name: String::from("title"),
+                        ..Default::default()
+                    })],
+                )]),
+                ..Default::default()
+            }),
+        ],
+    });
+    assert_eq!(
+        interface,
+        "export interface Page {
+  readonly _content_type_uid: 'page';
+  readonly links: ReadonlyArray<{
+    readonly link: ContentstackLink;
+  }>;
+  readonly sections: ReadonlyArray<{
+    readonly hero: {
+      readonly title: string;
+      readonly _metadata: { readonly uid: string };
+    };
+  }>;
+}"
+    );
+}
+
+#[test]
+fn test_readonly_builtins() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        readonly: true,
+        ..Default::default()
+    });
+    let builtins = generator.get_builtins();
+    assert!(builtins.contains("  readonly uid: string;\n"));
+    assert!(builtins.contains("  readonly tags: readonly string[];\n"));
+    assert!(builtins.contains("  readonly ACL: readonly any[] | object;\n"));
+    assert!(builtins.contains("  readonly dimension?: {\n    readonly height: number;\n"));
+    assert!(builtins.contains("  readonly [key: string]: any;\n"));
+}
+
+#[test]
+fn test_generate_registry() {
+    let generator = TypescriptGenerator::new(None, None);
+    let content_types = vec![
+        GenerateConfig {
+            name: String::from("author"),
+            title: None,
+            description: None,
+            editor_url: None,
+            fields: vec![],
+        },
+        GenerateConfig {
+            name: String::from("blog_post"),
+            title: None,
+            description: None,
+            editor_url: None,
+            fields: vec![],
+        },
+    ];
+    assert_eq!(
+        generator.generate_registry(&content_types),
+        "export type ContentTypeUid = 'author' | 'blog_post';
+
+export type AnyEntry = Author | BlogPost;
+
+export interface ContentTypeMap {
+  author: Author;
+  blog_post: BlogPost;
+}"
+    );
+}
+
+#[test]
+fn test_generate_registry_with_options() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        common: GeneratorOptions {
+            prefix: String::from("CS"),
+            ..Default::default()
+        },
+        declaration: DeclarationStyle::Type,
+        ..Default::default()
+    });
+    let content_types = vec![GenerateConfig {
+        name: String::from("landing-page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    }];
+    assert_eq!(
+        generator.generate_registry(&content_types),
+        "export type CSContentTypeUid = 'landing-page';
+
+export type CSAnyEntry = CSLandingPage;
+
+export type CSContentTypeMap = {
+  'landing-page': CSLandingPage;
+};"
+    );
+}
+
+#[test]
+fn test_registry_names_clash_with_content_types() {
+    let generator = TypescriptGenerator::new(None, None);
+    let definition = |name: &str| GenerateConfig {
+        name: String::from(name),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    };
+    assert!(
+        generator
+            .check_names(&[definition("seo")], &[definition("page")])
+            .is_ok()
+    );
+    let error = generator
+        .check_names(&[], &[definition("page"), definition("any_entry")])
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Output error: AnyEntry would be declared for both content type `any_entry` and the content type registry; rename one of them"
+    );
+}
+
+#[test]
+fn test_hoisted_select_names_clash_with_content_types() {
+    let definition = |name: &str, fields: Vec<Field>| GenerateConfig {
+        name: String::from(name),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields,
+    };
+    let category = Field::Text(FieldConfig {
+        name: String::from("category"),
+        path: vec![String::from("blog_post"), String::from("category")],
+        enumeration: Some(vec![String::from("news")]),
+        ..Default::default()
+    });
+    let content_types = [
+        definition("blog_post", vec![category]),
+        definition("blog_post_category", vec![]),
+    ];
+
+    // Inline selects declare nothing of their own
+    let generator = TypescriptGenerator::new(None, None);
+    assert!(generator.check_names(&[], &content_types).is_ok());
+    for selects in [SelectStyle::Const, SelectStyle::Enum] {
+        let generator = TypescriptGenerator::with_options(TypescriptOptions {
+            selects,
+            ..Default::default()
+        });
+        let error = generator.check_names(&[], &content_types).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Output error: BlogPostCategory would be declared for both content type `blog_post_category` and select field `blog_post.category`; rename one of them"
+        );
+    }
+}
+
+#[test]
+fn test_generate_empty_registry() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_registry(&[]),
+        "export type ContentTypeUid = never;
+
+export type AnyEntry = never;
+
+export interface ContentTypeMap {
+}"
+    );
+}
+
+#[test]
+fn test_generate_blocks_as_union() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_blocks(&FieldConfig {
+            name: String::from("sections"),
+            optional: true,
+            multiple: true,
+            block_types: Some(vec![
+                (
+                    String::from("hero"),
+                    vec![Field::Text(FieldConfig {
+                        name: String::from("title"),
+                        ..Default::default()
+                    })],
+                ),
+                (String::from("divider"), vec![]),
+            ]),
+            ..Default::default()
+        }),
+        "sections?: Array<{
+  hero: {
+    title: string;
+    _metadata: { uid: string };
+  };
+} | {
+  divider: {
+    _metadata: { uid: string };
+  };
+}>;"
+    );
+}
+
+#[test]
+fn test_generate_blocks_without_block_types() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_blocks(&FieldConfig {
+            name: String::from("sections"),
+            block_types: Some(vec![]),
+            ..Default::default()
+        }),
+        "sections: Array<any>;"
+    );
+}
+
+fn blog_post_definition() -> GenerateConfig {
+    GenerateConfig {
+        name: String::from("blog_post"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::File(FieldConfig {
+                name: String::from("image"),
+                ..Default::default()
+            }),
+            Field::Global(FieldConfig {
+                name: String::from("seo"),
+                reference_to: vec![String::from("seo")],
+                ..Default::default()
+            }),
+            Field::Group(FieldConfig {
+                name: String::from("meta"),
+                fields: vec![
+                    Field::Reference(FieldConfig {
+                        name: String::from("author"),
+                        reference_to: vec![String::from("author")],
+                        ..Default::default()
+                    }),
+                    Field::Reference(FieldConfig {
+                        name: String::from("related"),
+                        multiple: true,
+                        reference_to: vec![String::from("blog_post")],
+                        ..Default::default()
+                    }),
+                    Field::Link(FieldConfig {
+                        name: String::from("link"),
+                        ..Default::default()
+                    }),
+                ],
+                ..Default::default()
+            }),
+        ],
+    }
+}
+
+#[test]
+fn test_generate_module_imports() {
+    let generator = TypescriptGenerator::new(Some("CS"), None);
+    let module = generator.generate_module(&blog_post_definition());
+    assert!(module.starts_with(
+        "import type { ContentstackFile, ContentstackLink } from './builtins';
+import type { CSAuthor } from './author';
+import type { CSSeo } from './seo';
+
+export interface CSBlogPost {
+"
+    ));
+    assert!(module.ends_with("}\n"));
+}
+
+#[test]
+fn test_generate_module_without_imports() {
+    let generator = TypescriptGenerator::new(None, None);
+    let module = generator.generate_module(&GenerateConfig {
+        name: String::from("author"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("name"),
+            ..Default::default()
+        })],
+    });
+    assert_eq!(
+        module,
+        "export interface Author {
+  _content_type_uid: 'author';
+  name: string;
+}
+"
+    );
+}
+
+#[test]
+fn test_generate_module_imports_mapped_builtins() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        file_type: Some(String::from("Asset")),
+        date_type: DateType::Branded,
+        ..Default::default()
+    });
+    let module = generator.generate_module(&GenerateConfig {
+        name: String::from("event"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::File(FieldConfig {
+                name: String::from("poster"),
+                ..Default::default()
+            }),
+            Field::Date(FieldConfig {
+                name: String::from("starts_at"),
+                ..Default::default()
+            }),
+        ],
+    });
+    assert!(module.starts_with("import type { IsoDateString } from './builtins';\n\n"));
+}
+
+#[test]
+fn test_generate_index() {
+    let generator = TypescriptGenerator::new(None, None);
+    let global_fields = vec![GenerateConfig {
+        name: String::from("seo"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    }];
+    let content_types = vec![
+        GenerateConfig {
+            name: String::from("author"),
+            title: None,
+            description: None,
+            editor_url: None,
+            fields: vec![],
+        },
+        blog_post_definition(),
+    ];
+    assert_eq!(
+        generator.generate_index(&global_fields, &content_types),
+        "export * from './builtins';
+export * from './seo';
+export * from './author';
+export * from './blog_post';
+
+import type { Author } from './author';
+import type { BlogPost } from './blog_post';
+
+export type ContentTypeUid = 'author' | 'blog_post';
+
+export type AnyEntry = Author | BlogPost;
+
+export interface ContentTypeMap {
+  author: Author;
+  blog_post: BlogPost;
+}
+"
+    );
+    assert_eq!(generator.get_module_file_name("blog_post"), "blog_post.ts");
+}
+
+#[test]
+fn test_wrap_ambient() {
+    let content =
+        "export interface Author {\n  name: string;\n}\n\nexport type AnyEntry = Author;\n";
+
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(generator.wrap_ambient(content), content);
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        ambient: Ambient::Declarations,
+        ..Default::default()
+    });
+    assert_eq!(generator.wrap_ambient(content), content);
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        ambient: Ambient::Module(String::from("@acme/cms-types")),
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.wrap_ambient(content),
+        "declare module '@acme/cms-types' {
+  export interface Author {
+    name: string;
+  }
+
+  export type AnyEntry = Author;
+}
+"
+    );
+
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        ambient: Ambient::Namespace(String::from("Cms")),
+        ..Default::default()
+    });
+    assert!(
+        generator
+            .wrap_ambient(content)
+            .starts_with("declare namespace Cms {\n  export interface Author {\n")
+    );
+}
+
+#[test]
+fn test_ambient_const_selects_have_no_initializer() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        selects: SelectStyle::Const,
+        ambient: Ambient::Declarations,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&select_definition());
+    assert!(interface.starts_with(
+        "export const BlogPostStatusChoices: readonly ['draft', 'in review'];
+export type BlogPostStatus = typeof BlogPostStatusChoices[number];
+"
+    ));
+    assert_eq!(
+        generator.get_module_file_name("blog_post"),
+        "blog_post.d.ts"
+    );
+}
+
+#[test]
+fn test_generate_used_builtins_only() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        builtins: BuiltinsMode::Used,
+        ..Default::default()
+    });
+    let definition = blog_post_definition();
+    let builtins = generator.generate_builtins(&[&definition]).unwrap();
+    assert!(builtins.contains("export interface ContentstackFile {"));
+    assert!(builtins.contains("export interface PublishDetails {"));
+    assert!(builtins.contains("export interface ContentstackLink {"));
+    assert!(!builtins.contains("ContentstackJson"));
+
+    let definition = GenerateConfig {
+        name: String::from("author"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("name"),
+            ..Default::default()
+        })],
+    };
+    assert_eq!(generator.generate_builtins(&[&definition]), None);
+}
+
+#[test]
+fn test_omit_builtins() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        builtins: BuiltinsMode::None,
+        ..Default::default()
+    });
+    let definition = blog_post_definition();
+    assert_eq!(generator.generate_builtins(&[&definition]), None);
+    assert_eq!(generator.generate_builtins_import(&[&definition]), "");
+    assert!(generator.generate_module(&definition).starts_with(
+        "import type { Author } from './author';
+import type { Seo } from './seo';
+"
+    ));
+    assert!(
+        generator
+            .generate_index(&[], &[definition])
+            .starts_with("export * from './blog_post';\n")
+    );
+}
+
+#[test]
+fn test_import_builtins_from_module() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        builtins_import: Some(String::from("@acme/cms-builtins")),
+        ..Default::default()
+    });
+    let definition = blog_post_definition();
+    assert_eq!(generator.generate_builtins(&[&definition]), None);
+    assert_eq!(
+        generator.generate_builtins_import(&[&definition]),
+        "import type { ContentstackFile, ContentstackLink } from '@acme/cms-builtins';\n"
+    );
+    assert!(generator.generate_module(&definition).starts_with(
+        "import type { ContentstackFile, ContentstackLink } from '@acme/cms-builtins';\n"
+    ));
+}
+
+#[test]
+fn test_rich_field_jsdoc() {
+    let generator = TypescriptGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_text(&FieldConfig {
+            name: String::from("summary"),
+            display_name: Some(String::from("Summary")),
+            description: Some(String::from("[Deprecated] Shown in cards /* and */ lists")),
+            instruction: Some(String::from("Keep it short")),
+            path: vec![String::from("blog_post"), String::from("summary")],
+            ..Default::default()
+        }),
+        "/**
+ * Summary (`blog_post.summary`)
+ *
+ * Shown in cards /* and *\\/ lists
+ *
+ * Keep it short
+ *
+ * @deprecated
+ */
+summary: string;"
+    );
+}
+
+#[test]
+fn test_rich_interface_jsdoc() {
+    let generator = TypescriptGenerator::new(None, None);
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("author"),
+        title: Some(String::from("Author")),
+        description: Some(String::from("People writing posts")),
+        editor_url: Some(String::from(
+            "https://app.contentstack.com/#!/stack/key/content-type/author/content-type-builder",
+        )),
+        fields: vec![],
+    });
+    assert!(interface.starts_with(
+        "/**
+ * Author (`author`)
+ *
+ * People writing posts
+ *
+ * @see https://app.contentstack.com/#!/stack/key/content-type/author/content-type-builder
+ */
+export interface Author {
+"
+    ));
+}
+
+#[test]
+fn test_formatting_options() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        selects: SelectStyle::Enum,
+        formatting: Formatting {
+            indent: Indent::Tabs,
+            quotes: QuoteStyle::Double,
+            semicolons: false,
+            trailing_commas: false,
+        },
+        ..Default::default()
+    });
+    assert_eq!(
+        generator.generate_interface(&select_definition()),
+        "export enum BlogPostStatus {
+\tDraft = \"draft\",
+\tInReview = \"in review\"
+}
+
+export enum BlogPostLayoutColumns {
+\t_1 = 1,
+\t_2 = 2
+}
+
+export interface BlogPost {
+\t_content_type_uid: \"blog_post\"
+\tstatus: BlogPostStatus
+\tlayout: {
+\t\tcolumns: Array<BlogPostLayoutColumns>
+\t}
+}"
+    );
+    assert_eq!(
+        generator.generate_registry(&[blog_post_definition()]),
+        "export type ContentTypeUid = \"blog_post\"
+
+export type AnyEntry = BlogPost
+
+export interface ContentTypeMap {
+\tblog_post: BlogPost
+}"
+    );
+}
+
+#[test]
+fn test_double_quotes_escape_only_double_quotes() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        formatting: Formatting {
+            indent: Indent::Spaces(4),
+            quotes: QuoteStyle::Double,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let output = generator.generate_interface(&GenerateConfig {
+        name: String::from("it's \"quoted\""),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    });
+    assert!(output.contains("\n    _content_type_uid: \"it's \\\"quoted\\\"\";\n"));
+}
+
+#[test]
+fn test_editable_tags() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        editable_tags: true,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("page"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![
+            Field::Group(FieldConfig {
+                name: String::from("seo"),
+                fields: vec![Field::Text(FieldConfig {
+                    name: String::from("title"),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }),
+            Field::Blocks(FieldConfig {
+                name: String::from("sections"),
+                block_types: Some(vec![(
+                    String::from("hero"),
+                    vec![Field::Text(FieldConfig {
+                        name: String::from("heading"),
+                        ..Default::default()
+                    })],
+                )]),
+                ..Default::default()
+            }),
+        ],
+    });
+    assert_eq!(
+        interface,
+        "export interface Page {
+  _content_type_uid: 'page';
+  seo: {
+    title: string;
+    $?: { [K in 'title']?: { 'data-cslp': string } };
+  };
+  sections: Array<{
+    hero: {
+      heading: string;
+      $?: { [K in 'heading']?: { 'data-cslp': string } };
+      _metadata: { uid: string };
+    };
+  }>;
+  $?: { [K in 'seo' | 'sections']?: { 'data-cslp': string } };
+}"
+    );
+}
+
+#[test]
+fn test_field_paths() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        field_paths: true,
+        ..Default::default()
+    });
+    let mut definition = blog_post_definition();
+    definition.fields.push(Field::Blocks(FieldConfig {
+        name: String::from("sections"),
+        block_types: Some(vec![(
+            String::from("hero"),
+            vec![Field::Reference(FieldConfig {
+                name: String::from("cta"),
+                reference_to: vec![String::from("page")],
+                ..Default::default()
+            })],
+        )]),
+        ..Default::default()
+    }));
+    let interface = generator.generate_interface(&definition);
+    assert!(interface.ends_with(
+        "}
+
+export type BlogPostFieldPath = 'image' | 'seo' | `seo.${SeoFieldPath}` | 'meta' | 'meta.author' | 'meta.related' | 'meta.link' | 'sections' | 'sections.hero' | 'sections.hero.cta';
+export type BlogPostReferencePath = `seo.${SeoReferencePath}` | 'meta.author' | 'meta.related' | 'sections.hero.cta';"
+    ));
+
+    let module = generator.generate_module(&definition);
+    assert!(module.contains("import type { Seo, SeoFieldPath, SeoReferencePath } from './seo';\n"));
+    assert!(module.contains("import type { Author } from './author';\n"));
+}
+
+#[test]
+fn test_field_paths_without_references() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        field_paths: true,
+        ..Default::default()
+    });
+    let interface = generator.generate_interface(&GenerateConfig {
+        name: String::from("author"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("name"),
+            ..Default::default()
+        })],
+    });
+    assert!(interface.ends_with(
+        "export type AuthorFieldPath = 'name';
+export type AuthorReferencePath = never;"
+    ));
+}
+
+#[test]
+fn test_type_guards() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        type_guards: true,
+        ..Default::default()
+    });
+    let registry = generator.generate_registry(&[blog_post_definition()]);
+    assert!(registry.ends_with(
+        "}
+
+export function isBlogPost(entry: { _content_type_uid: string }): entry is BlogPost {
+  return entry._content_type_uid === 'blog_post';
+}
+
+export function assertContentType<U extends ContentTypeUid>(entry: { _content_type_uid: string }, uid: U): asserts entry is ContentTypeMap[U] {
+  if (entry._content_type_uid !== uid) {
+    throw new Error(`Expected a ${uid} entry, got ${entry._content_type_uid}`);
+  }
+}"
+    ));
+}
+
+#[test]
+fn test_ambient_output_omits_type_guards() {
+    let generator = TypescriptGenerator::with_options(TypescriptOptions {
+        type_guards: true,
+        ambient: Ambient::Declarations,
+        ..Default::default()
+    });
+    let registry = generator.generate_registry(&[blog_post_definition()]);
+    assert!(!registry.contains("export function"));
+    assert!(registry.ends_with("blog_post: BlogPost;\n}"));
+}
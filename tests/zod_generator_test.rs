@@ -0,0 +1,278 @@
+mod common;
+
+use common::blog_post_definition;
+use cs_ts::generator::typescript::{DateType, TypescriptOptions};
+use cs_ts::generator::{
+    Field, FieldConfig, GenerateConfig, Generator, GeneratorOptions, Nullability, ZodGenerator,
+};
+use serde_json::{Value, json};
+
+#[test]
+fn test_generate_schema() {
+    let generator = ZodGenerator::new(None, None);
+    assert_eq!(
+        generator.generate_interface(&blog_post_definition()),
+        "/** Blog Post (`blog_post`) */
+export const BlogPostSchema = z.object({
+  _content_type_uid: z.literal('blog_post').optional(),
+  title: z.string(),
+  status: z.enum(['draft', 'in review']).optional(),
+  type: z.string(),
+  rating: z.union([z.literal(1), z.literal(2.5)]),
+  /** Read time (`blog_post.Read-Time`) */
+  'Read-Time': z.number().optional(),
+  author: z.array(z.union([z.lazy((): z.ZodTypeAny => AuthorSchema), z.object({ _content_type_uid: z.literal('author'), uid: z.string() })])),
+  related: z.union([z.lazy((): z.ZodTypeAny => BlogPostSchema), z.object({ _content_type_uid: z.literal('blog_post'), uid: z.string() }), z.lazy((): z.ZodTypeAny => PageSchema), z.object({ _content_type_uid: z.literal('page'), uid: z.string() })]).optional(),
+  seo: z.lazy((): z.ZodTypeAny => SeoSchema),
+  meta: z.array(z.object({
+    featured: z.boolean(),
+  })),
+  sections: z.array(z.object({
+    hero: z.object({
+      image: ContentstackFileSchema,
+      _metadata: z.object({ uid: z.string() }),
+    }),
+  })).optional(),
+});"
+    );
+}
+
+#[test]
+fn test_generate_blocks_schema() {
+    let generator = ZodGenerator::new(None, None);
+    let blocks = generator.generate_blocks(&FieldConfig {
+        name: String::from("sections"),
+        optional: true,
+        block_types: Some(vec![
+            (
+                String::from("hero"),
+                vec![Field::Text(FieldConfig {
+                    name: String::from("heading"),
+                    ..Default::default()
+                })],
+            ),
+            (String::from("divider"), vec![]),
+        ]),
+        ..Default::default()
+    });
+    assert_eq!(
+        blocks,
+        "sections: z.array(z.union([z.object({
+  hero: z.object({
+    heading: z.string(),
+    _metadata: z.object({ uid: z.string() }),
+  }),
+}), z.object({
+  divider: z.object({
+    _metadata: z.object({ uid: z.string() }),
+  }),
+})])).optional()"
+    );
+}
+
+#[test]
+fn test_nullability_modes() {
+    let optional = FieldConfig {
+        name: String::from("summary"),
+        optional: true,
+        ..Default::default()
+    };
+    for (nullability, expected) in [
+        (Nullability::Optional, "summary: z.string().optional()"),
+        (Nullability::Nullable, "summary: z.string().nullable()"),
+        (
+            Nullability::OptionalNullable,
+            "summary: z.string().nullish()",
+        ),
+    ] {
+        let generator = ZodGenerator::with_options(TypescriptOptions {
+            common: GeneratorOptions {
+                nullability,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert_eq!(generator.generate_text(&optional), expected);
+    }
+}
+
+#[test]
+fn test_date_types() {
+    let published = FieldConfig {
+        name: String::from("published"),
+        ..Default::default()
+    };
+    for (date_type, expected) in [
+        (DateType::String, "published: z.string()"),
+        (DateType::Date, "published: z.coerce.date()"),
+        (
+            DateType::Branded,
+            "published: z.string().brand<'IsoDateString'>()",
+        ),
+    ] {
+        let generator = ZodGenerator::with_options(TypescriptOptions {
+            date_type,
+            ..Default::default()
+        });
+        assert_eq!(generator.generate_date(&published), expected);
+    }
+}
+
+#[test]
+fn test_generate_document() {
+    let generator = ZodGenerator::new(Some("CS"), None);
+    let document = generator.generate_document(&[], &[blog_post_definition()]);
+    assert!(document.starts_with(
+        "import { z } from 'zod';
+
+export const PublishDetailsSchema = z.object({"
+    ));
+    assert!(document.contains("  publish_details: PublishDetailsSchema,\n"));
+    assert!(document.contains("export const CSBlogPostSchema = z.object({\n"));
+    assert!(document.ends_with(
+        "export const CSContentTypeSchemas = {
+  blog_post: CSBlogPostSchema,
+} as const;
+"
+    ));
+    assert_eq!(generator.get_file_extension(), "zod.ts");
+}
+
+/// Keys of the top-level `z.object` schema named `name` that are neither optional nor nullish
+fn required_keys<'a>(document: &'a str, name: &str) -> Vec<&'a str> {
+    let start = document
+        .find(&format!("export const {name} = z.object({{\n"))
+        .expect("schema should be declared");
+    // Each property starts on a line of its own and may close several lines later
+    let mut properties: Vec<(&str, &str)> = Vec::new();
+    for line in document[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| *line != "});")
+    {
+        let Some(line) = line.strip_prefix("  ") else {
+            continue;
+        };
+        if line.starts_with('}') {
+            if let Some(property) = properties.last_mut() {
+                property.1 = line;
+            }
+        } else if !line.starts_with([' ', '/']) {
+            properties.push((line.split(':').next().unwrap_or_default(), line));
+        }
+    }
+    properties
+        .into_iter()
+        .filter(|(_, end)| !end.ends_with(".optional(),") && !end.ends_with(".nullish(),"))
+        .map(|(key, _)| key)
+        .collect()
+}
+
+#[test]
+fn test_schemas_accept_delivery_api_entry() {
+    let generator = ZodGenerator::new(None, None);
+    let seo = GenerateConfig {
+        name: String::from("seo"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("meta_title"),
+            ..Default::default()
+        })],
+    };
+    let document = generator.generate_document(&[seo], &[blog_post_definition()]);
+
+    // As returned by the Content Delivery API without `include_content_type`
+    let entry: Value = json!({
+        "uid": "blt0123456789abcdef",
+        "title": "Hello",
+        "type": "news",
+        "rating": 2.5,
+        "author": [{ "uid": "blt0fedcba987654321", "_content_type_uid": "author" }],
+        "seo": { "meta_title": "Hello" },
+        "meta": [{ "featured": true }],
+        "locale": "en-us",
+    });
+    assert_eq!(
+        required_keys(&document, "BlogPostSchema"),
+        ["title", "type", "rating", "author", "seo", "meta"]
+    );
+    for key in required_keys(&document, "BlogPostSchema") {
+        assert!(
+            entry.get(key).is_some(),
+            "{key} is required but not in the entry"
+        );
+    }
+    for key in required_keys(&document, "SeoSchema") {
+        assert!(
+            entry["seo"].get(key).is_some(),
+            "{key} is required but not in seo"
+        );
+    }
+    assert!(!document.contains("z.literal('seo')"));
+}
+
+#[test]
+fn test_lazy_only_for_cycles() {
+    let generator = ZodGenerator::new(None, None);
+    let definition = |name: &str, reference_to: &str| GenerateConfig {
+        name: String::from(name),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Reference(FieldConfig {
+            name: String::from("link"),
+            reference_to: vec![String::from(reference_to)],
+            ..Default::default()
+        })],
+    };
+    let seo = GenerateConfig {
+        name: String::from("seo"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![],
+    };
+    let document = generator.generate_document(
+        &[seo],
+        &[
+            blog_post_definition(),
+            definition("author", "author_bio"),
+            definition("author_bio", "author"),
+        ],
+    );
+    // Referenced schemas are declared first and used directly
+    assert!(document.contains("  seo: SeoSchema,\n"));
+    assert!(document.contains("  author: z.array(z.union([AuthorSchema, "));
+    assert!(
+        document.find("export const AuthorSchema").unwrap()
+            < document.find("export const BlogPostSchema").unwrap()
+    );
+    // Only the back edge of a cycle, and a self-reference, need `z.lazy`
+    assert!(document.contains("  link: z.union([z.lazy((): z.ZodTypeAny => AuthorSchema), "));
+    assert!(document.contains("  link: z.union([AuthorBioSchema, "));
+    assert!(document.contains("  related: z.union([z.lazy((): z.ZodTypeAny => BlogPostSchema), "));
+}
+
+#[test]
+fn test_global_fields_have_no_content_type_uid() {
+    let generator = ZodGenerator::new(None, None);
+    let seo = GenerateConfig {
+        name: String::from("seo"),
+        title: None,
+        description: None,
+        editor_url: None,
+        fields: vec![Field::Text(FieldConfig {
+            name: String::from("keywords"),
+            ..Default::default()
+        })],
+    };
+    assert!(
+        generator
+            .generate_interface(&seo)
+            .contains("_content_type_uid: z.literal('seo').optional()")
+    );
+    let document = generator.generate_document(&[seo], &[]);
+    assert!(document.contains("export const SeoSchema = z.object({\n  keywords: z.string(),\n});"));
+}